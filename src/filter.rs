@@ -0,0 +1,208 @@
+use crate::rules::MatchResult;
+use crate::ChangeLevel;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+
+/// Include/exclude filters applied to routes and schemas before matching
+///
+/// Path globs treat `/` as a separator, so `*` matches a single path segment
+/// and `**` matches any number of them (e.g. `/api/v2/**`).
+#[derive(Debug, Clone, Default)]
+pub struct MatchFilter {
+    include_paths: Option<GlobSet>,
+    exclude_paths: Option<GlobSet>,
+    exclude_schemas: Option<GlobSet>,
+}
+
+impl MatchFilter {
+    pub fn new(
+        include_paths: &[String],
+        exclude_paths: &[String],
+        exclude_schemas: &[String],
+    ) -> Result<Self, String> {
+        Ok(Self {
+            include_paths: build_glob_set(include_paths, true)?,
+            exclude_paths: build_glob_set(exclude_paths, true)?,
+            exclude_schemas: build_glob_set(exclude_schemas, false)?,
+        })
+    }
+
+    /// Check if a route path passes the include/exclude path filters
+    pub fn is_path_included(&self, path: &str) -> bool {
+        let included = self
+            .include_paths
+            .as_ref()
+            .map(|globs| globs.is_match(path))
+            .unwrap_or(true);
+        let excluded = self
+            .exclude_paths
+            .as_ref()
+            .map(|globs| globs.is_match(path))
+            .unwrap_or(false);
+
+        included && !excluded
+    }
+
+    /// Check if a component schema passes the exclude schema filter
+    pub fn is_schema_included(&self, schema_name: &str) -> bool {
+        !self
+            .exclude_schemas
+            .as_ref()
+            .map(|globs| globs.is_match(schema_name))
+            .unwrap_or(false)
+    }
+}
+
+/// Drop violations below the given level and results left without violations
+///
+/// Change levels of the remaining results are recalculated from the kept violations.
+pub fn filter_by_min_level(results: Vec<MatchResult>, min_level: &ChangeLevel) -> Vec<MatchResult> {
+    results
+        .into_iter()
+        .filter_map(|result| {
+            let violations: Vec<_> = result
+                .violations
+                .into_iter()
+                .filter(|violation| violation.change_level().is_at_least(min_level))
+                .collect();
+
+            if violations.is_empty() {
+                return None;
+            }
+
+            let mut filtered = MatchResult::new(result.name, violations);
+            filtered.route = result.route;
+            Some(filtered)
+        })
+        .collect()
+}
+
+fn build_glob_set(patterns: &[String], literal_separator: bool) -> Result<Option<GlobSet>, String> {
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(literal_separator)
+            .build()
+            .map_err(|err| format!("Invalid glob pattern '{}'. Error: {}", pattern, err))?;
+        builder.add(glob);
+    }
+
+    builder
+        .build()
+        .map(Some)
+        .map_err(|err| format!("Failed to build glob patterns. Error: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::schema::{FormatChangedRule, SchemaAddedRule, SchemaRemovedRule};
+    use crate::rules::RuleViolation;
+
+    fn patterns(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_empty_filter_includes_everything() {
+        let filter = MatchFilter::default();
+
+        assert!(filter.is_path_included("/internal/health"));
+        assert!(filter.is_schema_included("InternalUser"));
+    }
+
+    #[test]
+    fn test_include_and_exclude_paths() {
+        let filter = MatchFilter::new(
+            &patterns(&["/api/v2/**"]),
+            &patterns(&["/api/v2/internal/**"]),
+            &[],
+        )
+        .unwrap();
+
+        assert!(filter.is_path_included("/api/v2/users/{id}"));
+        assert!(!filter.is_path_included("/api/v1/users"));
+        assert!(!filter.is_path_included("/api/v2/internal/debug"));
+    }
+
+    #[test]
+    fn test_single_star_does_not_cross_segments() {
+        let filter = MatchFilter::new(&[], &patterns(&["/internal/*"]), &[]).unwrap();
+
+        assert!(!filter.is_path_included("/internal/health"));
+        assert!(filter.is_path_included("/internal/admin/users"));
+    }
+
+    #[test]
+    fn test_exclude_schemas() {
+        let filter = MatchFilter::new(&[], &[], &patterns(&["Internal*", "Debug"])).unwrap();
+
+        assert!(!filter.is_schema_included("InternalUser"));
+        assert!(!filter.is_schema_included("Debug"));
+        assert!(filter.is_schema_included("User"));
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let result = MatchFilter::new(&patterns(&["/api/[v2"]), &[], &[]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_filter_by_min_level() {
+        let results = vec![
+            MatchResult::new(
+                "User".to_string(),
+                vec![
+                    RuleViolation::new(Box::new(SchemaRemovedRule {
+                        schema_name: "User".to_string(),
+                    })),
+                    RuleViolation::new(Box::new(FormatChangedRule {
+                        schema_name: "User".to_string(),
+                        property_path: "email".to_string(),
+                        old_format: Some("email".to_string()),
+                        new_format: None,
+                    })),
+                ],
+            ),
+            MatchResult::new(
+                "NewModel".to_string(),
+                vec![RuleViolation::new(Box::new(SchemaAddedRule {
+                    schema_name: "NewModel".to_string(),
+                }))],
+            ),
+        ];
+
+        let filtered = filter_by_min_level(results, &ChangeLevel::Breaking);
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "User");
+        assert_eq!(filtered[0].violations.len(), 1);
+        assert_eq!(filtered[0].change_level, ChangeLevel::Breaking);
+    }
+
+    #[test]
+    fn test_filter_by_min_level_recalculates_change_level() {
+        let results = vec![MatchResult::new(
+            "User".to_string(),
+            vec![
+                RuleViolation::new(Box::new(FormatChangedRule {
+                    schema_name: "User".to_string(),
+                    property_path: "email".to_string(),
+                    old_format: Some("email".to_string()),
+                    new_format: None,
+                })),
+                RuleViolation::new(Box::new(SchemaAddedRule {
+                    schema_name: "User".to_string(),
+                })),
+            ],
+        )];
+
+        let filtered = filter_by_min_level(results, &ChangeLevel::Change);
+        assert_eq!(filtered[0].violations.len(), 2);
+        assert_eq!(filtered[0].change_level, ChangeLevel::Warning);
+    }
+}
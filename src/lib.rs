@@ -1,11 +1,31 @@
+pub mod filter;
+pub mod loader;
+pub mod locate;
 pub mod matcher;
 pub mod render;
 pub mod rules;
+pub mod source;
 
 /// Level of specific change
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
 pub enum ChangeLevel {
     Breaking,
     Warning,
     Change,
 }
+
+impl ChangeLevel {
+    /// Severity rank of the level, higher is more severe
+    fn severity(&self) -> u8 {
+        match self {
+            ChangeLevel::Breaking => 2,
+            ChangeLevel::Warning => 1,
+            ChangeLevel::Change => 0,
+        }
+    }
+
+    /// Check if this level is at least as severe as the given one
+    pub fn is_at_least(&self, level: &ChangeLevel) -> bool {
+        self.severity() >= level.severity()
+    }
+}
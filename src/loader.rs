@@ -0,0 +1,354 @@
+mod bundle;
+mod kind;
+mod normalize;
+mod positions;
+mod swagger;
+
+pub use bundle::SOURCE_EXTENSION;
+pub use normalize::{CARRIED_KEYWORDS, KEYWORD_EXTENSION_PREFIX};
+pub use positions::{escape_pointer_segment, SourceMap};
+
+use oas3::OpenApiV3Spec;
+use std::fmt;
+use std::path::Path;
+
+/// Serialization format of a specification document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecFormat {
+    Json,
+    Yaml,
+}
+
+impl SpecFormat {
+    /// Format implied by the file extension, if any
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "json" => Some(SpecFormat::Json),
+            "yaml" | "yml" => Some(SpecFormat::Yaml),
+            _ => None,
+        }
+    }
+
+    /// Guess format by the first meaningful character of the content
+    fn sniff(content: &str) -> Self {
+        match content.trim_start().chars().next() {
+            Some('{') | Some('[') => SpecFormat::Json,
+            _ => SpecFormat::Yaml,
+        }
+    }
+}
+
+impl fmt::Display for SpecFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpecFormat::Json => write!(f, "JSON"),
+            SpecFormat::Yaml => write!(f, "YAML"),
+        }
+    }
+}
+
+/// Line and column (1-based) of a node or a problem in the source document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct SourceLocation {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Reason a specification could not be loaded
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoadError {
+    /// Content is neither valid JSON nor valid YAML
+    Syntax {
+        format: SpecFormat,
+        message: String,
+        location: Option<SourceLocation>,
+    },
+    /// Document is a Swagger specification older than 2.0
+    UnsupportedSwagger(String),
+    /// Document has no `openapi` version field
+    NotOpenApi,
+    /// Ref to another file can't be resolved
+    ExternalRef { reference: String, message: String },
+    /// Document declares OpenAPI 3.x but doesn't match its structure
+    InvalidOpenApi {
+        version: String,
+        message: String,
+        location: Option<SourceLocation>,
+    },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Syntax {
+                format,
+                message,
+                location,
+            } => {
+                write!(f, "Invalid {} syntax", format)?;
+                if let Some(location) = location {
+                    write!(f, " at {}", location)?;
+                }
+                write!(f, ": {}", message)
+            }
+            LoadError::UnsupportedSwagger(version) => write!(
+                f,
+                "Swagger {} is not supported, only Swagger 2.0 and OpenAPI 3.x are",
+                version
+            ),
+            LoadError::NotOpenApi => write!(
+                f,
+                "Document is not an OpenAPI specification (missing 'openapi' version field)"
+            ),
+            LoadError::ExternalRef { reference, message } => {
+                write!(f, "Failed to resolve '{}': {}", reference, message)
+            }
+            LoadError::InvalidOpenApi {
+                version,
+                message,
+                location,
+            } => {
+                write!(f, "Invalid OpenAPI {} document", version)?;
+                if let Some(location) = location {
+                    write!(f, " at {}", location)?;
+                }
+                write!(f, ": {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Parse an OpenAPI document, detecting its format by content.
+///
+/// The file extension of `path` is only a hint: content that is not valid in the
+/// hinted format is retried as JSON, then as YAML (which also accepts JSON content).
+/// Swagger 2.0 documents are converted to OpenAPI 3.0 in memory.
+pub fn parse_spec(content: &str, path: &Path) -> Result<(OpenApiV3Spec, SpecFormat), LoadError> {
+    parse_spec_bundled(content, path, |file| {
+        Err(format!("Reading \"{}\" is not supported", file.display()))
+    })
+}
+
+/// Parse an OpenAPI document like [`parse_spec`], bundling refs to other files into it.
+///
+/// `read` loads a referenced file by its path relative to the directory of `path`.
+pub fn parse_spec_bundled<F>(
+    content: &str,
+    path: &Path,
+    read: F,
+) -> Result<(OpenApiV3Spec, SpecFormat), LoadError>
+where
+    F: Fn(&Path) -> Result<String, String>,
+{
+    let (mut value, format) = parse_document(content, path)?;
+
+    let converted = swagger::is_swagger_2(&value);
+    if converted {
+        log::info!("Converting Swagger 2.0 document to OpenAPI 3.0");
+        value = swagger::convert(&value);
+    } else if let Some(version) = swagger::version(&value) {
+        return Err(LoadError::UnsupportedSwagger(version));
+    }
+
+    let version = match value.get("openapi") {
+        Some(serde_json::Value::String(version)) => version.clone(),
+        Some(other) => other.to_string(),
+        None => return Err(LoadError::NotOpenApi),
+    };
+
+    let bundled = bundle::bundle(&mut value, path, read)?;
+    normalize::normalize(&mut value);
+
+    match serde_json::from_value(value) {
+        Ok(spec) => Ok((spec, format)),
+        Err(err) => {
+            // Values carry no positions, so parse the raw content again to locate the error.
+            // Converted and bundled documents no longer match the raw content
+            let (message, location) = match format {
+                _ if converted || bundled => (err.to_string(), None),
+                SpecFormat::Json => match oas3::from_json(content) {
+                    Err(err) => json_error_parts(&err),
+                    Ok(_) => (err.to_string(), None),
+                },
+                SpecFormat::Yaml => match oas3::from_yaml(content) {
+                    Err(err) => yaml_error_parts(&err),
+                    Ok(_) => (err.to_string(), None),
+                },
+            };
+
+            Err(LoadError::InvalidOpenApi {
+                version,
+                message,
+                location,
+            })
+        }
+    }
+}
+
+/// Parse raw content into a generic document, trying the hinted format first
+fn parse_document(
+    content: &str,
+    path: &Path,
+) -> Result<(serde_json::Value, SpecFormat), LoadError> {
+    let hint = SpecFormat::from_path(path);
+
+    if hint != Some(SpecFormat::Yaml) {
+        if let Ok(value) = serde_json::from_str(content) {
+            return Ok((value, SpecFormat::Json));
+        }
+    }
+
+    let yaml_error = match serde_yaml::from_str(content) {
+        Ok(value) => return Ok((value, SpecFormat::Yaml)),
+        Err(err) => err,
+    };
+
+    // Report the error of the format the document most likely is
+    let format = hint.unwrap_or_else(|| SpecFormat::sniff(content));
+    let (message, location) = match format {
+        SpecFormat::Json => match serde_json::from_str::<serde_json::Value>(content) {
+            Err(err) => json_error_parts(&err),
+            Ok(_) => unreachable!("JSON content was rejected above"),
+        },
+        SpecFormat::Yaml => yaml_error_parts(&yaml_error),
+    };
+
+    Err(LoadError::Syntax {
+        format,
+        message,
+        location,
+    })
+}
+
+fn json_error_parts(err: &serde_json::Error) -> (String, Option<SourceLocation>) {
+    if err.line() == 0 {
+        return (err.to_string(), None);
+    }
+
+    let location = SourceLocation {
+        line: err.line(),
+        column: err.column(),
+    };
+    (strip_location_suffix(&err.to_string()), Some(location))
+}
+
+fn yaml_error_parts(err: &serde_yaml::Error) -> (String, Option<SourceLocation>) {
+    match err.location() {
+        Some(location) => (
+            strip_location_suffix(&err.to_string()),
+            Some(SourceLocation {
+                line: location.line(),
+                column: location.column(),
+            }),
+        ),
+        None => (err.to_string(), None),
+    }
+}
+
+/// serde errors end with " at line X column Y", which is reported separately
+fn strip_location_suffix(message: &str) -> String {
+    match message.rfind(" at line ") {
+        Some(index) => message[..index].to_string(),
+        None => message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINIMAL_JSON: &str =
+        r#"{"openapi": "3.0.0", "info": {"title": "Test", "version": "1.0.0"}, "paths": {}}"#;
+
+    const MINIMAL_YAML: &str =
+        "openapi: 3.1.0\ninfo:\n  title: Test\n  version: 1.0.0\npaths: {}\n";
+
+    #[test]
+    fn test_detects_json_without_extension() {
+        let (spec, format) = parse_spec(MINIMAL_JSON, Path::new("openapi")).unwrap();
+        assert_eq!(format, SpecFormat::Json);
+        assert_eq!(spec.info.title, "Test");
+    }
+
+    #[test]
+    fn test_detects_yaml_with_misleading_extension() {
+        let (spec, format) = parse_spec(MINIMAL_YAML, Path::new("openapi.json.bak")).unwrap();
+        assert_eq!(format, SpecFormat::Yaml);
+        assert_eq!(spec.openapi, "3.1.0");
+
+        let (spec, _) = parse_spec(MINIMAL_JSON, Path::new("spec.yaml")).unwrap();
+        assert_eq!(spec.openapi, "3.0.0");
+    }
+
+    #[test]
+    fn test_syntax_error_has_location() {
+        let content = "{\n  \"openapi\": \"3.0.0\",\n  \"info\": {\n}";
+        let err = parse_spec(content, Path::new("openapi.json")).unwrap_err();
+
+        match err {
+            LoadError::Syntax {
+                format, location, ..
+            } => {
+                assert_eq!(format, SpecFormat::Json);
+                assert_eq!(location.map(|l| l.line), Some(4));
+            }
+            other => panic!("Unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_swagger_2_is_converted() {
+        let content = "swagger: '2.0'\ninfo:\n  title: Test\n  version: '1.0'\npaths: {}\ndefinitions:\n  Pet:\n    type: object\n";
+        let (spec, format) = parse_spec(content, Path::new("spec.txt")).unwrap();
+        assert_eq!(format, SpecFormat::Yaml);
+        assert!(spec.components.unwrap().schemas.contains_key("Pet"));
+    }
+
+    #[test]
+    fn test_unquoted_swagger_version_is_converted() {
+        let content = "swagger: 2.0\ninfo:\n  title: Test\n  version: '1.0'\npaths: {}\n";
+        let (spec, _) = parse_spec(content, Path::new("spec.yaml")).unwrap();
+        assert!(spec.openapi.starts_with("3.0"));
+    }
+
+    #[test]
+    fn test_old_swagger_is_reported() {
+        let content = r#"{"swagger": "1.2", "info": {"title": "Test", "version": "1.0"}}"#;
+        let err = parse_spec(content, Path::new("spec.txt")).unwrap_err();
+        assert_eq!(err, LoadError::UnsupportedSwagger("1.2".to_string()));
+    }
+
+    #[test]
+    fn test_not_openapi_document() {
+        let err = parse_spec("name: something\n", Path::new("spec.yaml")).unwrap_err();
+        assert_eq!(err, LoadError::NotOpenApi);
+    }
+
+    #[test]
+    fn test_invalid_openapi_has_location() {
+        let content = "openapi: 3.0.0\ninfo:\n  title: Test\npaths: {}\n";
+        let err = parse_spec(content, Path::new("openapi.yaml")).unwrap_err();
+
+        match err {
+            LoadError::InvalidOpenApi {
+                version,
+                message,
+                location,
+            } => {
+                assert_eq!(version, "3.0.0");
+                assert!(message.contains("version"), "{}", message);
+                assert!(location.is_some());
+            }
+            other => panic!("Unexpected error: {:?}", other),
+        }
+    }
+}
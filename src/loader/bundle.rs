@@ -0,0 +1,475 @@
+//! Bundling of external file `$ref`s into a single document
+
+use super::kind::Kind;
+use super::{parse_document, LoadError};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+/// Extension recording the file a hoisted component was loaded from
+pub const SOURCE_EXTENSION: &str = "x-apidrift-source";
+
+/// Component sections that hoisted refs can be placed into
+const SECTIONS: [&str; 6] = [
+    "schemas",
+    "parameters",
+    "responses",
+    "requestBodies",
+    "headers",
+    "examples",
+];
+
+/// Replace refs to other local files with refs to components of the root document.
+///
+/// Referenced values are hoisted into the matching `components` section (named after the
+/// last pointer segment or the file name), so schemas keep their names in reports and
+/// recursive refs between files stay refs. Hoisted schemas get an `x-apidrift-source`
+/// extension with the original location. `read` loads a file by its path relative to the
+/// directory of the root document. Returns whether the document had external refs.
+pub fn bundle<F>(document: &mut Value, root_path: &Path, read: F) -> Result<bool, LoadError>
+where
+    F: Fn(&Path) -> Result<String, String>,
+{
+    if !has_external_refs(document) {
+        return Ok(false);
+    }
+
+    let root = PathBuf::from(root_path.file_name().unwrap_or_default());
+    let components = match document.get_mut("components").map(Value::take) {
+        Some(Value::Object(components)) => components,
+        _ => Map::new(),
+    };
+
+    let mut bundler = Bundler {
+        read,
+        root: root.clone(),
+        documents: HashMap::new(),
+        hoisted: HashMap::new(),
+        inlining: HashSet::new(),
+        components,
+    };
+
+    // Components that are plain external refs keep their own name
+    for section in SECTIONS {
+        for name in names_of(&bundler.components, section) {
+            let entry = &bundler.components[section][&name];
+            if let Some(target) = bundler.external_target(entry, &root) {
+                let reference = format!("#/components/{}/{}", section, name);
+                bundler.hoisted.insert(target, reference);
+            }
+        }
+    }
+
+    for section in SECTIONS {
+        let kind = Kind::Other.child(section).element();
+        for name in names_of(&bundler.components, section) {
+            let mut entry = bundler.components[section][&name].take();
+            match bundler.external_target(&entry, &root) {
+                Some((file, pointer)) => {
+                    entry = bundler.load_hoisted(&file, &pointer, kind)?;
+                }
+                None => bundler.walk(&mut entry, &root, kind)?,
+            }
+            bundler.components[section][&name] = entry;
+        }
+    }
+
+    if let Value::Object(object) = document {
+        for (key, value) in object.iter_mut() {
+            bundler.walk(value, &root, Kind::Other.child(key))?;
+        }
+    }
+
+    if !bundler.components.is_empty() {
+        document["components"] = Value::Object(bundler.components);
+    }
+
+    Ok(true)
+}
+
+struct Bundler<F> {
+    read: F,
+    /// Root document path, relative to its own directory
+    root: PathBuf,
+    /// Parsed external documents by path relative to the root directory
+    documents: HashMap<PathBuf, Value>,
+    /// Component refs of already hoisted targets, also breaking ref cycles
+    hoisted: HashMap<(PathBuf, String), String>,
+    /// Targets being inlined right now, to detect cycles through inlined values
+    inlining: HashSet<(PathBuf, String)>,
+    components: Map<String, Value>,
+}
+
+impl<F> Bundler<F>
+where
+    F: Fn(&Path) -> Result<String, String>,
+{
+    /// Rewrite external refs found in `value`, which belongs to `file`
+    fn walk(&mut self, value: &mut Value, file: &Path, kind: Kind) -> Result<(), LoadError> {
+        if kind == Kind::Data {
+            return Ok(());
+        }
+
+        if let Some((target_file, pointer)) = self.ref_target(value, file) {
+            // Refs back into the root document become local refs
+            if target_file == self.root {
+                value["$ref"] = Value::String(format!("#{}", pointer));
+                return Ok(());
+            }
+
+            let key = (target_file.clone(), pointer.clone());
+
+            if let Some(reference) = self.hoisted.get(&key) {
+                value["$ref"] = Value::String(reference.clone());
+                return Ok(());
+            }
+
+            match kind.section() {
+                Some(section) => {
+                    let name = self.unique_name(section, &target_file, &pointer);
+                    let reference = format!("#/components/{}/{}", section, name);
+                    self.hoisted.insert(key, reference.clone());
+
+                    // Reserve the name before resolving, nested refs may point back here
+                    let entries = self
+                        .components
+                        .entry(section)
+                        .or_insert_with(|| Value::Object(Map::new()));
+                    entries[&name] = Value::Null;
+
+                    let hoisted = self.load_hoisted(&target_file, &pointer, kind)?;
+                    self.components[section][&name] = hoisted;
+                    value["$ref"] = Value::String(reference);
+                }
+                None => {
+                    if !self.inlining.insert(key.clone()) {
+                        return Err(external_error(
+                            &target_file,
+                            &pointer,
+                            "Circular reference can't be inlined".to_string(),
+                        ));
+                    }
+                    *value = self.load_hoisted(&target_file, &pointer, kind)?;
+                    self.inlining.remove(&key);
+                }
+            }
+            return Ok(());
+        }
+
+        match value {
+            Value::Object(object) => {
+                for (key, child) in object.iter_mut() {
+                    self.walk(child, file, kind.child(key))?;
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.walk(item, file, kind.element())?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Load the referenced value with its own external refs resolved
+    fn load_hoisted(&mut self, file: &Path, pointer: &str, kind: Kind) -> Result<Value, LoadError> {
+        let mut value = self.resolve(file, pointer)?;
+        self.walk(&mut value, file, kind)?;
+
+        if kind == Kind::Schema {
+            if let Value::Object(object) = &mut value {
+                object.insert(
+                    SOURCE_EXTENSION.to_string(),
+                    Value::String(source_name(file, pointer)),
+                );
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn resolve(&mut self, file: &Path, pointer: &str) -> Result<Value, LoadError> {
+        if !self.documents.contains_key(file) {
+            let content = (self.read)(file).map_err(|err| external_error(file, pointer, err))?;
+            let (document, _) = parse_document(&content, file)
+                .map_err(|err| external_error(file, pointer, err.to_string()))?;
+            self.documents.insert(file.to_path_buf(), document);
+        }
+
+        self.documents[file]
+            .pointer(pointer)
+            .cloned()
+            .ok_or_else(|| external_error(file, pointer, "Pointer not found".to_string()))
+    }
+
+    /// File and JSON pointer of a ref to another file (or inside a non-root file)
+    fn external_target(&self, value: &Value, file: &Path) -> Option<(PathBuf, String)> {
+        self.ref_target(value, file)
+            .filter(|(target_file, _)| *target_file != self.root)
+    }
+
+    /// File and JSON pointer of a ref to a local file, the root document included
+    fn ref_target(&self, value: &Value, file: &Path) -> Option<(PathBuf, String)> {
+        let reference = value.get("$ref")?.as_str()?;
+        let (target, pointer) = match reference.split_once('#') {
+            Some((target, pointer)) => (target, pointer),
+            None => (reference, ""),
+        };
+
+        if target.contains("://") {
+            return None;
+        }
+
+        let target_file = if target.is_empty() {
+            file.to_path_buf()
+        } else {
+            normalize(&file.parent().unwrap_or(Path::new("")).join(target))
+        };
+
+        Some((target_file, pointer.to_string()))
+    }
+
+    /// Component name for a hoisted value that doesn't clash with existing components
+    fn unique_name(&self, section: &str, file: &Path, pointer: &str) -> String {
+        let base_name = pointer
+            .rsplit('/')
+            .find(|segment| !segment.is_empty())
+            .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+            .or_else(|| {
+                file.file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+            })
+            .unwrap_or_else(|| "External".to_string());
+
+        let taken = |name: &str| {
+            self.components
+                .get(section)
+                .and_then(|entries| entries.get(name))
+                .is_some()
+        };
+
+        let mut name = base_name.clone();
+        let mut index = 2;
+        while taken(&name) {
+            name = format!("{}_{}", base_name, index);
+            index += 1;
+        }
+        name
+    }
+}
+
+fn names_of(components: &Map<String, Value>, section: &str) -> Vec<String> {
+    components
+        .get(section)
+        .and_then(Value::as_object)
+        .map(|entries| entries.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+fn has_external_refs(value: &Value) -> bool {
+    match value {
+        Value::Object(object) => object
+            .iter()
+            .any(|(key, child)| match (key.as_str(), child) {
+                ("$ref", Value::String(reference)) => !reference.starts_with('#'),
+                _ => has_external_refs(child),
+            }),
+        Value::Array(items) => items.iter().any(has_external_refs),
+        _ => false,
+    }
+}
+
+/// Resolve `.` and `..` without touching the filesystem, since files may come from git
+fn normalize(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(result.components().next_back(), Some(Component::Normal(_))) {
+                    result.pop();
+                } else {
+                    result.push(component);
+                }
+            }
+            other => result.push(other),
+        }
+    }
+    result
+}
+
+fn source_name(file: &Path, pointer: &str) -> String {
+    if pointer.is_empty() {
+        file.display().to_string()
+    } else {
+        format!("{}#{}", file.display(), pointer)
+    }
+}
+
+fn external_error(file: &Path, pointer: &str, message: String) -> LoadError {
+    LoadError::ExternalRef {
+        reference: source_name(file, pointer),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn files(path: &Path) -> Result<String, String> {
+        match path.to_str() {
+            Some("schemas/user.yaml") => Ok(concat!(
+                "User:\n",
+                "  type: object\n",
+                "  properties:\n",
+                "    address:\n",
+                "      $ref: '#/Address'\n",
+                "    friends:\n",
+                "      type: array\n",
+                "      items:\n",
+                "        $ref: '#/User'\n",
+                "    tags:\n",
+                "      $ref: '../common/tag.yaml'\n",
+                "Address:\n",
+                "  type: object\n",
+            )
+            .to_string()),
+            Some("common/tag.yaml") => Ok("type: array\nitems:\n  type: string\n".to_string()),
+            Some("schemas/order.yaml") => Ok(concat!(
+                "type: object\n",
+                "properties:\n",
+                "  shipping:\n",
+                "    $ref: '../openapi.yaml#/components/schemas/Address'\n",
+            )
+            .to_string()),
+            _ => Err(format!("No such file: {}", path.display())),
+        }
+    }
+
+    #[test]
+    fn test_hoists_external_schemas_into_components() {
+        let mut document = json!({
+            "openapi": "3.0.0",
+            "paths": {
+                "/users": {
+                    "get": {
+                        "responses": {
+                            "200": {
+                                "description": "OK",
+                                "content": {
+                                    "application/json": {
+                                        "schema": { "$ref": "./schemas/user.yaml#/User" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        bundle(&mut document, Path::new("openapi.yaml"), files).unwrap();
+
+        let schema_ref = &document["paths"]["/users"]["get"]["responses"]["200"]["content"]
+            ["application/json"]["schema"]["$ref"];
+        assert_eq!(schema_ref, "#/components/schemas/User");
+
+        let schemas = &document["components"]["schemas"];
+        assert_eq!(
+            schemas["User"]["properties"]["address"]["$ref"],
+            "#/components/schemas/Address"
+        );
+        assert_eq!(
+            schemas["User"]["properties"]["friends"]["items"]["$ref"],
+            "#/components/schemas/User"
+        );
+        assert_eq!(schemas["tag"]["items"]["type"], "string");
+        assert_eq!(schemas["User"][SOURCE_EXTENSION], "schemas/user.yaml#/User");
+    }
+
+    #[test]
+    fn test_component_ref_keeps_component_name() {
+        let mut document = json!({
+            "openapi": "3.0.0",
+            "paths": {},
+            "components": {
+                "schemas": {
+                    "Account": { "$ref": "schemas/user.yaml#/User" },
+                    "Profile": {
+                        "type": "object",
+                        "properties": { "user": { "$ref": "schemas/user.yaml#/User" } }
+                    }
+                }
+            }
+        });
+
+        bundle(&mut document, Path::new("specs/openapi.yaml"), files).unwrap();
+
+        let schemas = &document["components"]["schemas"];
+        assert_eq!(schemas["Account"]["type"], "object");
+        assert_eq!(
+            schemas["Account"]["properties"]["friends"]["items"]["$ref"],
+            "#/components/schemas/Account"
+        );
+        assert_eq!(
+            schemas["Profile"]["properties"]["user"]["$ref"],
+            "#/components/schemas/Account"
+        );
+        assert!(schemas.get("User").is_none());
+    }
+
+    #[test]
+    fn test_ref_back_into_root_document_becomes_local() {
+        let mut document = json!({
+            "openapi": "3.0.0",
+            "paths": {},
+            "components": {
+                "schemas": {
+                    "Address": { "type": "object" },
+                    "Order": { "$ref": "schemas/order.yaml" }
+                }
+            }
+        });
+
+        bundle(&mut document, Path::new("openapi.yaml"), files).unwrap();
+
+        let schemas = &document["components"]["schemas"];
+        assert_eq!(
+            schemas["Order"]["properties"]["shipping"]["$ref"],
+            "#/components/schemas/Address"
+        );
+        assert_eq!(schemas["Address"]["type"], "object");
+    }
+
+    #[test]
+    fn test_missing_file_is_reported() {
+        let mut document = json!({
+            "components": { "schemas": { "Pet": { "$ref": "pet.yaml#/Pet" } } }
+        });
+
+        let err = bundle(&mut document, Path::new("openapi.yaml"), files).unwrap_err();
+        assert_eq!(
+            err,
+            LoadError::ExternalRef {
+                reference: "pet.yaml#/Pet".to_string(),
+                message: "No such file: pet.yaml".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize(Path::new("schemas/../common/./tag.yaml")),
+            PathBuf::from("common/tag.yaml")
+        );
+        assert_eq!(
+            normalize(Path::new("../shared/tag.yaml")),
+            PathBuf::from("../shared/tag.yaml")
+        );
+    }
+}
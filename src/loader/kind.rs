@@ -0,0 +1,84 @@
+//! Structural kinds of values in an OpenAPI document
+
+/// What a value in the document describes, so schemas can be told apart from other objects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Other,
+    /// Example values and other data that may not be treated as document structure
+    Data,
+    Schema,
+    Schemas,
+    Parameter,
+    Parameters,
+    Response,
+    Responses,
+    RequestBody,
+    RequestBodies,
+    Header,
+    Headers,
+    Example,
+    Examples,
+}
+
+impl Kind {
+    /// Kind of the value under `key` of an object of this kind
+    pub fn child(self, key: &str) -> Kind {
+        match self {
+            Kind::Data => Kind::Data,
+            Kind::Schema => match key {
+                "properties" | "patternProperties" | "dependentSchemas" | "$defs"
+                | "definitions" => Kind::Schemas,
+                "example" | "examples" | "default" | "enum" | "const" | "required"
+                | "dependentRequired" => Kind::Data,
+                _ => Kind::Schema,
+            },
+            Kind::Example => Kind::Data,
+            Kind::Schemas
+            | Kind::Parameters
+            | Kind::Responses
+            | Kind::RequestBodies
+            | Kind::Headers
+            | Kind::Examples => self.element(),
+            Kind::Other | Kind::Parameter | Kind::Response | Kind::RequestBody | Kind::Header => {
+                match key {
+                    "schema" => Kind::Schema,
+                    "schemas" => Kind::Schemas,
+                    "parameters" => Kind::Parameters,
+                    "responses" => Kind::Responses,
+                    "requestBody" => Kind::RequestBody,
+                    "requestBodies" => Kind::RequestBodies,
+                    "headers" => Kind::Headers,
+                    "examples" => Kind::Examples,
+                    "example" => Kind::Data,
+                    _ => Kind::Other,
+                }
+            }
+        }
+    }
+
+    /// Kind of the elements of a collection of this kind
+    pub fn element(self) -> Kind {
+        match self {
+            Kind::Schemas => Kind::Schema,
+            Kind::Parameters => Kind::Parameter,
+            Kind::Responses => Kind::Response,
+            Kind::RequestBodies => Kind::RequestBody,
+            Kind::Headers => Kind::Header,
+            Kind::Examples => Kind::Example,
+            other => other,
+        }
+    }
+
+    /// Components section a value of this kind is hoisted into
+    pub fn section(self) -> Option<&'static str> {
+        match self {
+            Kind::Schema => Some("schemas"),
+            Kind::Parameter => Some("parameters"),
+            Kind::Response => Some("responses"),
+            Kind::RequestBody => Some("requestBodies"),
+            Kind::Header => Some("headers"),
+            Kind::Example => Some("examples"),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Normalization of schema keywords the OpenAPI model doesn't keep as-is
+
+use super::kind::Kind;
+use serde_json::Value;
+
+/// Prefix of extensions carrying JSON Schema keywords the OpenAPI model has no field for
+pub const KEYWORD_EXTENSION_PREFIX: &str = "x-apidrift-";
+
+/// JSON Schema 2020-12 keywords kept as extensions, so rules can still compare them
+pub const CARRIED_KEYWORDS: [&str; 7] = [
+    "if",
+    "then",
+    "else",
+    "dependentRequired",
+    "dependentSchemas",
+    "unevaluatedProperties",
+    "$defs",
+];
+
+/// Marks 3.0 `nullable: true` schemas without a `type` to add "null" to
+const NULLABLE_KEYWORD: &str = "nullable";
+
+/// Normalize all schemas of a document:
+/// - 3.0 `nullable: true` becomes a 3.1 type array with "null", so both styles compare equal
+/// - keywords in [`CARRIED_KEYWORDS`] are moved to `x-apidrift-<keyword>` extensions
+pub fn normalize(document: &mut Value) {
+    walk(document, Kind::Other);
+}
+
+fn walk(value: &mut Value, kind: Kind) {
+    if kind == Kind::Data {
+        return;
+    }
+
+    match value {
+        Value::Object(object) => {
+            for (key, child) in object.iter_mut() {
+                walk(child, kind.child(key));
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                walk(item, kind.element());
+            }
+        }
+        _ => return,
+    }
+
+    if kind == Kind::Schema {
+        normalize_schema(value);
+    }
+}
+
+fn normalize_schema(schema: &mut Value) {
+    let Value::Object(object) = schema else {
+        return;
+    };
+
+    if let Some(nullable) = object.remove(NULLABLE_KEYWORD) {
+        if nullable == Value::Bool(true) {
+            match object.get_mut("type") {
+                Some(Value::String(schema_type)) => {
+                    let types = vec![Value::String(schema_type.clone()), Value::from("null")];
+                    object.insert("type".to_string(), Value::Array(types));
+                }
+                Some(Value::Array(types)) => {
+                    if !types.contains(&Value::from("null")) {
+                        types.push(Value::from("null"));
+                    }
+                }
+                // Refs and compositions can't carry a type, keep the flag for nullability checks
+                _ => {
+                    object.insert(
+                        format!("{}{}", KEYWORD_EXTENSION_PREFIX, NULLABLE_KEYWORD),
+                        Value::Bool(true),
+                    );
+                }
+            }
+        }
+    }
+
+    for keyword in CARRIED_KEYWORDS {
+        if let Some(value) = object.remove(keyword) {
+            object.insert(format!("{}{}", KEYWORD_EXTENSION_PREFIX, keyword), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_nullable_becomes_type_array() {
+        let mut document = json!({
+            "components": {
+                "schemas": {
+                    "User": {
+                        "type": "object",
+                        "properties": {
+                            "name": { "type": "string", "nullable": true },
+                            "nullable": { "type": "boolean" },
+                            "tags": { "type": ["array", "null"], "nullable": true },
+                            "manager": { "allOf": [{ "$ref": "#/components/schemas/User" }], "nullable": true }
+                        },
+                        "example": { "nullable": true }
+                    }
+                }
+            }
+        });
+
+        normalize(&mut document);
+
+        let properties = &document["components"]["schemas"]["User"]["properties"];
+        assert_eq!(properties["name"]["type"], json!(["string", "null"]));
+        assert_eq!(properties["nullable"]["type"], "boolean");
+        assert_eq!(properties["tags"]["type"], json!(["array", "null"]));
+        assert_eq!(properties["manager"]["x-apidrift-nullable"], true);
+        assert_eq!(
+            document["components"]["schemas"]["User"]["example"],
+            json!({ "nullable": true })
+        );
+    }
+
+    #[test]
+    fn test_unsupported_keywords_are_carried_as_extensions() {
+        let mut document = json!({
+            "components": {
+                "schemas": {
+                    "Payment": {
+                        "type": "object",
+                        "properties": { "if": { "type": "string" } },
+                        "if": { "properties": { "kind": { "const": "card" } } },
+                        "then": { "required": ["card"] },
+                        "dependentRequired": { "card": ["cvv"] },
+                        "unevaluatedProperties": false
+                    }
+                }
+            }
+        });
+
+        normalize(&mut document);
+
+        let payment = &document["components"]["schemas"]["Payment"];
+        assert!(payment.get("if").is_none());
+        assert_eq!(payment["x-apidrift-then"], json!({ "required": ["card"] }));
+        assert_eq!(
+            payment["x-apidrift-dependentRequired"],
+            json!({ "card": ["cvv"] })
+        );
+        assert_eq!(payment["x-apidrift-unevaluatedProperties"], false);
+        assert_eq!(payment["properties"]["if"]["type"], "string");
+    }
+}
@@ -0,0 +1,468 @@
+//! Source positions of document nodes, by JSON pointer
+
+use super::{SourceLocation, SpecFormat};
+use std::collections::HashMap;
+
+/// Component sections moved to the top level of Swagger 2.0 documents
+const SWAGGER_SECTIONS: [(&str, &str); 3] = [
+    ("/components/schemas/", "/definitions/"),
+    ("/components/parameters/", "/parameters/"),
+    ("/components/responses/", "/responses/"),
+];
+
+/// Line and column of every object member and array element of a source document.
+///
+/// Members are located at their key, elements at their value. Positions are collected
+/// by a lightweight scan of the raw content: JSON is scanned fully, YAML in block style
+/// only (nodes inside flow collections and multi-line scalars are located at their parent).
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    positions: HashMap<String, SourceLocation>,
+}
+
+impl SourceMap {
+    pub fn new(content: &str, format: SpecFormat) -> Self {
+        let mut positions = HashMap::new();
+        match format {
+            SpecFormat::Json => JsonScanner::new(content, &mut positions).scan(),
+            SpecFormat::Yaml => scan_yaml(content, &mut positions),
+        }
+        Self { positions }
+    }
+
+    /// Position of the node at `pointer`, or of its closest located parent.
+    ///
+    /// Pointers are into the loaded document, so components of converted Swagger 2.0
+    /// documents are looked up in their original sections.
+    pub fn locate(&self, pointer: &str) -> Option<SourceLocation> {
+        let pointer = self.original_pointer(pointer);
+        let mut pointer = pointer.as_str();
+
+        while !pointer.is_empty() {
+            if let Some(location) = self.positions.get(pointer) {
+                return Some(*location);
+            }
+            pointer = &pointer[..pointer.rfind('/').unwrap_or(0)];
+        }
+        None
+    }
+
+    fn original_pointer(&self, pointer: &str) -> String {
+        if self.positions.contains_key("/swagger") {
+            for (section, original) in SWAGGER_SECTIONS {
+                if let Some(rest) = pointer.strip_prefix(section) {
+                    return format!("{}{}", original, rest);
+                }
+            }
+
+            // Body and formData parameters are moved out of `parameters` by the conversion,
+            // so indices of the others may not match the source: locate them at the list
+            let segments: Vec<&str> = pointer.split('/').collect();
+            let list = match segments.as_slice() {
+                ["", "paths", _, "parameters", _, ..] => Some(4),
+                ["", "paths", _, _, "parameters", _, ..] => Some(5),
+                _ => None,
+            };
+            if let Some(length) = list {
+                return segments[..length].join("/");
+            }
+        }
+        pointer.to_string()
+    }
+}
+
+/// Escape a key for use as a JSON pointer segment
+pub fn escape_pointer_segment(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+struct JsonScanner<'a> {
+    chars: Vec<char>,
+    index: usize,
+    line: usize,
+    column: usize,
+    positions: &'a mut HashMap<String, SourceLocation>,
+}
+
+impl<'a> JsonScanner<'a> {
+    fn new(content: &str, positions: &'a mut HashMap<String, SourceLocation>) -> Self {
+        Self {
+            chars: content.chars().collect(),
+            index: 0,
+            line: 1,
+            column: 1,
+            positions,
+        }
+    }
+
+    fn scan(mut self) {
+        self.skip_whitespace();
+        self.value("");
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.index).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.index += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn location(&self) -> SourceLocation {
+        SourceLocation {
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    /// Scan a value, returns false on malformed content
+    fn value(&mut self, pointer: &str) -> bool {
+        match self.peek() {
+            Some('{') => self.object(pointer),
+            Some('[') => self.array(pointer),
+            Some('"') => self.string().is_some(),
+            Some(_) => {
+                while self
+                    .peek()
+                    .is_some_and(|c| !c.is_whitespace() && !matches!(c, ',' | '}' | ']'))
+                {
+                    self.bump();
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn object(&mut self, pointer: &str) -> bool {
+        self.bump();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('}') => {
+                    self.bump();
+                    return true;
+                }
+                Some(',') => {
+                    self.bump();
+                }
+                Some('"') => {
+                    let location = self.location();
+                    let Some(key) = self.string() else {
+                        return false;
+                    };
+                    let member = format!("{}/{}", pointer, escape_pointer_segment(&key));
+
+                    self.skip_whitespace();
+                    if self.bump() != Some(':') {
+                        return false;
+                    }
+                    self.skip_whitespace();
+                    self.positions.entry(member.clone()).or_insert(location);
+                    if !self.value(&member) {
+                        return false;
+                    }
+                }
+                _ => return false,
+            }
+        }
+    }
+
+    fn array(&mut self, pointer: &str) -> bool {
+        self.bump();
+        let mut index = 0;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(']') => {
+                    self.bump();
+                    return true;
+                }
+                Some(',') => {
+                    self.bump();
+                }
+                Some(_) => {
+                    let element = format!("{}/{}", pointer, index);
+                    self.positions.insert(element.clone(), self.location());
+                    if !self.value(&element) {
+                        return false;
+                    }
+                    index += 1;
+                }
+                None => return false,
+            }
+        }
+    }
+
+    /// Scan a string literal and decode it
+    fn string(&mut self) -> Option<String> {
+        let start = self.index;
+        self.bump();
+        loop {
+            match self.bump()? {
+                '"' => break,
+                '\\' => {
+                    self.bump()?;
+                }
+                _ => {}
+            }
+        }
+
+        let literal: String = self.chars[start..self.index].iter().collect();
+        serde_json::from_str(&literal).ok()
+    }
+}
+
+/// Block node opened by a YAML line: a mapping key or a sequence item
+struct YamlNode {
+    indent: usize,
+    pointer: String,
+    is_item: bool,
+    /// Number of sequence items seen under this node
+    items: usize,
+}
+
+fn scan_yaml(content: &str, positions: &mut HashMap<String, SourceLocation>) {
+    let mut stack: Vec<YamlNode> = Vec::new();
+    // Lines indented deeper than this belong to a block scalar
+    let mut block_scalar_indent: Option<usize> = None;
+
+    for (line_index, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let mut indent = line.len() - trimmed.len();
+
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        match block_scalar_indent {
+            Some(scalar_indent) if indent > scalar_indent => continue,
+            _ => block_scalar_indent = None,
+        }
+        if trimmed.starts_with("---") || trimmed.starts_with("...") {
+            stack.clear();
+            continue;
+        }
+
+        let mut rest = trimmed;
+        loop {
+            let location = SourceLocation {
+                line: line_index + 1,
+                column: indent + 1,
+            };
+
+            if rest == "-" || rest.starts_with("- ") {
+                // Items at the indent of their parent key belong to it
+                while stack.last().is_some_and(|node| {
+                    node.indent > indent || (node.indent == indent && node.is_item)
+                }) {
+                    stack.pop();
+                }
+                let pointer = match stack.last_mut() {
+                    Some(parent) => {
+                        parent.items += 1;
+                        format!("{}/{}", parent.pointer, parent.items - 1)
+                    }
+                    None => "/0".to_string(),
+                };
+
+                let value = rest[1..].trim_start();
+                indent += rest.len() - value.len();
+                positions.entry(pointer.clone()).or_insert(SourceLocation {
+                    line: location.line,
+                    column: indent + 1,
+                });
+                stack.push(YamlNode {
+                    indent: location.column - 1,
+                    pointer,
+                    is_item: true,
+                    items: 0,
+                });
+
+                // The item may start a mapping on the same line
+                rest = value;
+                continue;
+            }
+
+            let Some((key, value)) = split_yaml_key(rest) else {
+                break;
+            };
+            while stack.last().is_some_and(|node| node.indent >= indent) {
+                stack.pop();
+            }
+            let parent = stack.last().map(|node| node.pointer.as_str()).unwrap_or("");
+            let pointer = format!("{}/{}", parent, escape_pointer_segment(&key));
+            positions.entry(pointer.clone()).or_insert(location);
+            stack.push(YamlNode {
+                indent,
+                pointer,
+                is_item: false,
+                items: 0,
+            });
+
+            if value.starts_with('|') || value.starts_with('>') {
+                block_scalar_indent = Some(indent);
+            }
+            break;
+        }
+    }
+}
+
+/// Split a `key: value` line into the unquoted key and the value
+fn split_yaml_key(line: &str) -> Option<(String, &str)> {
+    let (key, rest) = match line.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let end = line[1..].find(quote)? + 1;
+            let key = &line[1..end];
+            let key = if quote == '"' {
+                serde_json::from_str(&line[..=end]).unwrap_or_else(|_| key.to_string())
+            } else {
+                key.replace("''", "'")
+            };
+            (key, line[end + 1..].trim_start())
+        }
+        _ => {
+            let end = line
+                .match_indices(':')
+                .map(|(index, _)| index)
+                .find(|index| matches!(line[index + 1..].chars().next(), None | Some(' ')))?;
+            (line[..end].trim_end().to_string(), &line[end..])
+        }
+    };
+
+    let value = rest.strip_prefix(':')?;
+    if !(value.is_empty() || value.starts_with(' ')) {
+        return None;
+    }
+    Some((key, value.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_of(map: &SourceMap, pointer: &str) -> Option<usize> {
+        map.locate(pointer).map(|location| location.line)
+    }
+
+    #[test]
+    fn test_json_positions() {
+        let content = r#"{
+  "paths": {
+    "/users/{id}": {
+      "get": {"parameters": [{"name": "id"}, {"name": "q"}]}
+    }
+  },
+  "components": {"schemas": {
+    "User": {
+      "properties": {"name": {"type": "string"}}
+    }
+  }}
+}"#;
+        let map = SourceMap::new(content, SpecFormat::Json);
+
+        assert_eq!(
+            line_of(&map, "/paths/~1users~1{id}/get/parameters/1"),
+            Some(4)
+        );
+        assert_eq!(
+            map.locate("/components/schemas/User/properties/name"),
+            Some(SourceLocation {
+                line: 9,
+                column: 22
+            })
+        );
+        // Missing nodes are located at their closest parent
+        assert_eq!(line_of(&map, "/components/schemas/User/required"), Some(8));
+        assert_eq!(line_of(&map, "/webhooks"), None);
+    }
+
+    #[test]
+    fn test_yaml_positions() {
+        let content = "\
+openapi: 3.0.0
+paths:
+  /users:
+    get:
+      description: |
+        type: not a key
+      parameters:
+      - name: id
+        in: query
+      - name: q
+components:
+  schemas:
+    'User':
+      required: [name]
+      properties:
+        name:
+          type: string
+";
+        let map = SourceMap::new(content, SpecFormat::Yaml);
+
+        assert_eq!(line_of(&map, "/paths/~1users/get/parameters/0"), Some(8));
+        assert_eq!(
+            map.locate("/paths/~1users/get/parameters/1"),
+            Some(SourceLocation {
+                line: 10,
+                column: 9
+            })
+        );
+        assert_eq!(
+            line_of(&map, "/paths/~1users/get/description/type"),
+            Some(5)
+        );
+        assert_eq!(
+            line_of(&map, "/components/schemas/User/properties/name/type"),
+            Some(17)
+        );
+        assert_eq!(
+            line_of(&map, "/components/schemas/User/required/0"),
+            Some(14)
+        );
+    }
+
+    #[test]
+    fn test_swagger_components_are_located_in_definitions() {
+        let content = "swagger: '2.0'\ndefinitions:\n  Pet:\n    type: object\n";
+        let map = SourceMap::new(content, SpecFormat::Yaml);
+
+        assert_eq!(line_of(&map, "/components/schemas/Pet/type"), Some(4));
+    }
+
+    #[test]
+    fn test_swagger_parameters_are_located_at_their_list() {
+        let content = concat!(
+            "swagger: '2.0'\n",
+            "paths:\n",
+            "  /pets:\n",
+            "    post:\n",
+            "      parameters:\n",
+            "        - name: body\n",
+            "          in: body\n",
+            "        - name: limit\n",
+            "          in: query\n",
+        );
+        let map = SourceMap::new(content, SpecFormat::Yaml);
+
+        // `limit` is the first converted parameter, but the second in the source
+        assert_eq!(
+            line_of(&map, "/paths/~1pets/post/parameters/0/name"),
+            Some(5)
+        );
+        assert_eq!(line_of(&map, "/paths/~1pets/post/responses"), Some(4));
+    }
+}
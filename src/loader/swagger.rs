@@ -0,0 +1,767 @@
+//! In-memory conversion of Swagger 2.0 documents to the OpenAPI 3.0 structure
+
+use serde_json::{json, Map, Value};
+
+/// OpenAPI version assigned to converted documents
+const CONVERTED_VERSION: &str = "3.0.3";
+
+const DEFAULT_MEDIA_TYPE: &str = "application/json";
+const FORM_URLENCODED: &str = "application/x-www-form-urlencoded";
+const MULTIPART_FORM: &str = "multipart/form-data";
+
+const METHODS: [&str; 7] = ["get", "put", "post", "delete", "options", "head", "patch"];
+
+/// Parameter fields that describe the value and move into `schema` in OpenAPI 3
+const SCHEMA_FIELDS: [&str; 16] = [
+    "type",
+    "format",
+    "items",
+    "default",
+    "maximum",
+    "exclusiveMaximum",
+    "minimum",
+    "exclusiveMinimum",
+    "maxLength",
+    "minLength",
+    "pattern",
+    "maxItems",
+    "minItems",
+    "uniqueItems",
+    "enum",
+    "multipleOf",
+];
+
+/// Declared `swagger` version of a document, a string or a number when the YAML value is unquoted
+pub fn version(document: &Value) -> Option<String> {
+    match document.get("swagger")? {
+        Value::String(version) => Some(version.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Check if a parsed document declares `swagger: "2.0"`, or another 2.x version
+pub fn is_swagger_2(document: &Value) -> bool {
+    matches!(version(document), Some(version) if version == "2" || version.starts_with("2."))
+}
+
+/// Convert a Swagger 2.0 document to an equivalent OpenAPI 3.0 document
+///
+/// Covers what the matchers compare: `definitions` become `components.schemas`,
+/// body and formData parameters become `requestBody`, and `consumes`/`produces`
+/// become content types of request bodies and responses.
+pub fn convert(document: &Value) -> Value {
+    let converter = Converter::new(document);
+    let mut result = Map::new();
+
+    result.insert("openapi".to_string(), json!(CONVERTED_VERSION));
+    result.insert(
+        "info".to_string(),
+        document.get("info").cloned().unwrap_or_else(|| json!({})),
+    );
+
+    let servers = converter.servers();
+    if !servers.is_empty() {
+        result.insert("servers".to_string(), Value::Array(servers));
+    }
+
+    let mut paths = Map::new();
+    if let Some(Value::Object(source_paths)) = document.get("paths") {
+        for (path, path_item) in source_paths {
+            paths.insert(path.clone(), converter.path_item(path_item));
+        }
+    }
+    result.insert("paths".to_string(), Value::Object(paths));
+
+    let components = converter.components();
+    if !components.is_empty() {
+        result.insert("components".to_string(), Value::Object(components));
+    }
+
+    for key in ["security", "tags", "externalDocs"] {
+        if let Some(value) = document.get(key) {
+            result.insert(key.to_string(), value.clone());
+        }
+    }
+
+    for (key, value) in as_object(document) {
+        if key.starts_with("x-") {
+            result.insert(key.clone(), value.clone());
+        }
+    }
+
+    Value::Object(result)
+}
+
+struct Converter<'a> {
+    document: &'a Value,
+    consumes: Vec<String>,
+    produces: Vec<String>,
+}
+
+impl<'a> Converter<'a> {
+    fn new(document: &'a Value) -> Self {
+        Self {
+            document,
+            consumes: string_list(document.get("consumes")),
+            produces: string_list(document.get("produces")),
+        }
+    }
+
+    fn servers(&self) -> Vec<Value> {
+        let base_path = self
+            .document
+            .get("basePath")
+            .and_then(Value::as_str)
+            .unwrap_or("");
+
+        match self.document.get("host").and_then(Value::as_str) {
+            Some(host) => {
+                let schemes = string_list(self.document.get("schemes"));
+                let schemes = if schemes.is_empty() {
+                    vec!["https".to_string()]
+                } else {
+                    schemes
+                };
+                schemes
+                    .iter()
+                    .map(|scheme| json!({ "url": format!("{}://{}{}", scheme, host, base_path) }))
+                    .collect()
+            }
+            None if !base_path.is_empty() => vec![json!({ "url": base_path })],
+            None => vec![],
+        }
+    }
+
+    fn components(&self) -> Map<String, Value> {
+        let mut components = Map::new();
+
+        if let Some(Value::Object(definitions)) = self.document.get("definitions") {
+            let schemas = definitions
+                .iter()
+                .map(|(name, schema)| (name.clone(), convert_schema(schema)))
+                .collect();
+            components.insert("schemas".to_string(), Value::Object(schemas));
+        }
+
+        // Body parameters have no OpenAPI 3 parameter counterpart, so they become request bodies
+        if let Some(Value::Object(parameters)) = self.document.get("parameters") {
+            let mut converted_parameters = Map::new();
+            let mut request_bodies = Map::new();
+
+            for (name, parameter) in parameters {
+                match parameter.get("in").and_then(Value::as_str) {
+                    Some("body") => {
+                        request_bodies
+                            .insert(name.clone(), self.body_request(parameter, &self.consumes));
+                    }
+                    Some("formData") => {
+                        request_bodies.insert(
+                            name.clone(),
+                            self.form_request(&[parameter], &self.consumes),
+                        );
+                    }
+                    _ => {
+                        converted_parameters.insert(name.clone(), convert_parameter(parameter));
+                    }
+                }
+            }
+
+            if !converted_parameters.is_empty() {
+                components.insert(
+                    "parameters".to_string(),
+                    Value::Object(converted_parameters),
+                );
+            }
+            if !request_bodies.is_empty() {
+                components.insert("requestBodies".to_string(), Value::Object(request_bodies));
+            }
+        }
+
+        if let Some(Value::Object(responses)) = self.document.get("responses") {
+            let converted = responses
+                .iter()
+                .map(|(name, response)| (name.clone(), self.response(response, &self.produces)))
+                .collect();
+            components.insert("responses".to_string(), Value::Object(converted));
+        }
+
+        if let Some(Value::Object(security_definitions)) = self.document.get("securityDefinitions")
+        {
+            let converted = security_definitions
+                .iter()
+                .map(|(name, scheme)| (name.clone(), convert_security_scheme(scheme)))
+                .collect();
+            components.insert("securitySchemes".to_string(), Value::Object(converted));
+        }
+
+        components
+    }
+
+    fn path_item(&self, path_item: &Value) -> Value {
+        let mut result = Map::new();
+        let shared_parameters = array(path_item.get("parameters"));
+
+        for (key, value) in as_object(path_item) {
+            if METHODS.contains(&key.as_str()) {
+                result.insert(key.clone(), self.operation(value, shared_parameters));
+            } else if key == "parameters" {
+                let parameters: Vec<Value> = shared_parameters
+                    .iter()
+                    .filter(|p| !self.is_body_or_form_parameter(p))
+                    .map(|p| self.parameter_or_ref(p))
+                    .collect();
+                if !parameters.is_empty() {
+                    result.insert(key.clone(), Value::Array(parameters));
+                }
+            } else if key == "$ref" || key.starts_with("x-") {
+                result.insert(key.clone(), value.clone());
+            }
+        }
+
+        Value::Object(result)
+    }
+
+    fn operation(&self, operation: &Value, shared_parameters: &[Value]) -> Value {
+        let mut result = Map::new();
+        let consumes = string_list(operation.get("consumes"));
+        let consumes = if consumes.is_empty() {
+            &self.consumes
+        } else {
+            &consumes
+        };
+        let produces = string_list(operation.get("produces"));
+        let produces = if produces.is_empty() {
+            &self.produces
+        } else {
+            &produces
+        };
+
+        let operation_parameters = array(operation.get("parameters"));
+        let mut parameters = Vec::new();
+        let mut body_parameter = None;
+        let mut form_parameters = Vec::new();
+
+        // Other path-level parameters stay on the path item, shared body/form parameters
+        // move into the request body unless the operation declares its own
+        let has_own_body = operation_parameters
+            .iter()
+            .any(|p| self.is_body_or_form_parameter(p));
+        let shared = shared_parameters
+            .iter()
+            .filter(|p| !has_own_body && self.is_body_or_form_parameter(p));
+
+        for parameter in shared.chain(operation_parameters.iter()) {
+            let resolved = self.resolve_parameter(parameter);
+            match resolved.get("in").and_then(Value::as_str) {
+                Some("body") => body_parameter = Some(parameter),
+                Some("formData") => form_parameters.push(resolved),
+                _ => parameters.push(self.parameter_or_ref(parameter)),
+            }
+        }
+
+        for (key, value) in as_object(operation) {
+            match key.as_str() {
+                "consumes" | "produces" | "parameters" | "schemes" => {}
+                "responses" => {
+                    let responses = as_object(value)
+                        .iter()
+                        .map(|(status, response)| {
+                            (status.clone(), self.response_or_ref(response, produces))
+                        })
+                        .collect();
+                    result.insert(key.clone(), Value::Object(responses));
+                }
+                _ => {
+                    result.insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        if !parameters.is_empty() {
+            result.insert("parameters".to_string(), Value::Array(parameters));
+        }
+
+        if let Some(body) = body_parameter {
+            let request_body = match body.get("$ref").and_then(Value::as_str) {
+                Some(reference) => json!({ "$ref": convert_ref(reference, true) }),
+                None => self.body_request(body, consumes),
+            };
+            result.insert("requestBody".to_string(), request_body);
+        } else if !form_parameters.is_empty() {
+            result.insert(
+                "requestBody".to_string(),
+                self.form_request(&form_parameters, consumes),
+            );
+        }
+
+        Value::Object(result)
+    }
+
+    fn is_body_or_form_parameter(&self, parameter: &Value) -> bool {
+        matches!(
+            self.resolve_parameter(parameter)
+                .get("in")
+                .and_then(Value::as_str),
+            Some("body") | Some("formData")
+        )
+    }
+
+    /// Resolve `#/parameters/...` references to the global parameter definition
+    fn resolve_parameter<'b>(&'b self, parameter: &'b Value) -> &'b Value {
+        parameter
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|reference| reference.strip_prefix("#/parameters/"))
+            .and_then(|name| self.document.get("parameters")?.get(name))
+            .unwrap_or(parameter)
+    }
+
+    fn parameter_or_ref(&self, parameter: &Value) -> Value {
+        match parameter.get("$ref").and_then(Value::as_str) {
+            Some(reference) => json!({ "$ref": convert_ref(reference, false) }),
+            None => convert_parameter(parameter),
+        }
+    }
+
+    fn body_request(&self, parameter: &Value, consumes: &[String]) -> Value {
+        let schema = parameter
+            .get("schema")
+            .map(convert_schema)
+            .unwrap_or_else(|| json!({}));
+
+        let mut request_body = Map::new();
+        if let Some(description) = parameter.get("description") {
+            request_body.insert("description".to_string(), description.clone());
+        }
+        request_body.insert(
+            "content".to_string(),
+            content(&media_types(consumes, &[DEFAULT_MEDIA_TYPE]), &schema),
+        );
+        if let Some(required) = parameter.get("required") {
+            request_body.insert("required".to_string(), required.clone());
+        }
+
+        Value::Object(request_body)
+    }
+
+    /// Merge formData parameters into a single object schema request body
+    fn form_request(&self, parameters: &[&Value], consumes: &[String]) -> Value {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+
+        for parameter in parameters {
+            let Some(name) = parameter.get("name").and_then(Value::as_str) else {
+                continue;
+            };
+
+            let mut schema = parameter_schema(parameter);
+            if let Some(description) = parameter.get("description") {
+                schema.insert("description".to_string(), description.clone());
+            }
+            properties.insert(name.to_string(), Value::Object(schema));
+
+            if parameter.get("required").and_then(Value::as_bool) == Some(true) {
+                required.push(json!(name));
+            }
+        }
+
+        let mut schema = Map::new();
+        schema.insert("type".to_string(), json!("object"));
+        schema.insert("properties".to_string(), Value::Object(properties));
+        if !required.is_empty() {
+            schema.insert("required".to_string(), Value::Array(required));
+        }
+
+        let form_types: Vec<String> = consumes
+            .iter()
+            .filter(|t| t.as_str() == FORM_URLENCODED || t.as_str() == MULTIPART_FORM)
+            .cloned()
+            .collect();
+        let has_file = parameters
+            .iter()
+            .any(|p| p.get("type").and_then(Value::as_str) == Some("file"));
+        let default_type = if has_file {
+            MULTIPART_FORM
+        } else {
+            FORM_URLENCODED
+        };
+
+        json!({
+            "content": content(&media_types(&form_types, &[default_type]), &Value::Object(schema)),
+        })
+    }
+
+    fn response_or_ref(&self, response: &Value, produces: &[String]) -> Value {
+        match response.get("$ref").and_then(Value::as_str) {
+            Some(reference) => json!({ "$ref": convert_ref(reference, false) }),
+            None => self.response(response, produces),
+        }
+    }
+
+    fn response(&self, response: &Value, produces: &[String]) -> Value {
+        let mut result = Map::new();
+        result.insert(
+            "description".to_string(),
+            response
+                .get("description")
+                .cloned()
+                .unwrap_or_else(|| json!("")),
+        );
+
+        if let Some(schema) = response.get("schema") {
+            result.insert(
+                "content".to_string(),
+                content(
+                    &media_types(produces, &[DEFAULT_MEDIA_TYPE]),
+                    &convert_schema(schema),
+                ),
+            );
+        }
+
+        if let Some(Value::Object(headers)) = response.get("headers") {
+            let converted = headers
+                .iter()
+                .map(|(name, header)| {
+                    let mut result = Map::new();
+                    if let Some(description) = header.get("description") {
+                        result.insert("description".to_string(), description.clone());
+                    }
+                    result.insert(
+                        "schema".to_string(),
+                        Value::Object(parameter_schema(header)),
+                    );
+                    (name.clone(), Value::Object(result))
+                })
+                .collect();
+            result.insert("headers".to_string(), Value::Object(converted));
+        }
+
+        for (key, value) in as_object(response) {
+            if key.starts_with("x-") {
+                result.insert(key.clone(), value.clone());
+            }
+        }
+
+        Value::Object(result)
+    }
+}
+
+fn convert_parameter(parameter: &Value) -> Value {
+    let mut result = Map::new();
+
+    for (key, value) in as_object(parameter) {
+        if SCHEMA_FIELDS.contains(&key.as_str()) || key == "collectionFormat" {
+            continue;
+        }
+        result.insert(key.clone(), value.clone());
+    }
+
+    if parameter.get("in").and_then(Value::as_str) == Some("path") {
+        result.insert("required".to_string(), json!(true));
+    }
+
+    match parameter.get("collectionFormat").and_then(Value::as_str) {
+        Some("multi") => {
+            result.insert("style".to_string(), json!("form"));
+            result.insert("explode".to_string(), json!(true));
+        }
+        Some("ssv") => {
+            result.insert("style".to_string(), json!("spaceDelimited"));
+        }
+        Some("pipes") => {
+            result.insert("style".to_string(), json!("pipeDelimited"));
+        }
+        _ => {}
+    }
+
+    result.insert(
+        "schema".to_string(),
+        Value::Object(parameter_schema(parameter)),
+    );
+
+    Value::Object(result)
+}
+
+/// Collect value-describing fields of a non-body parameter or header into a schema
+fn parameter_schema(parameter: &Value) -> Map<String, Value> {
+    let schema: Map<String, Value> = as_object(parameter)
+        .iter()
+        .filter(|(key, _)| SCHEMA_FIELDS.contains(&key.as_str()))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+
+    match convert_schema(&Value::Object(schema)) {
+        Value::Object(schema) => schema,
+        _ => Map::new(),
+    }
+}
+
+/// Convert a Swagger schema: rewrite refs and map Swagger-only keywords
+fn convert_schema(schema: &Value) -> Value {
+    match schema {
+        Value::Object(object) => {
+            let mut result = Map::new();
+
+            for (key, value) in object {
+                match key.as_str() {
+                    "$ref" => {
+                        let reference = value.as_str().map(|r| convert_ref(r, false));
+                        result.insert(key.clone(), json!(reference));
+                    }
+                    "x-nullable" => {
+                        result.insert("nullable".to_string(), value.clone());
+                    }
+                    "type" if value.as_str() == Some("file") => {
+                        result.insert("type".to_string(), json!("string"));
+                        result.insert("format".to_string(), json!("binary"));
+                    }
+                    // Property names and examples are data, not schemas
+                    "properties" | "definitions" | "patternProperties" => {
+                        let converted = as_object(value)
+                            .iter()
+                            .map(|(name, schema)| (name.clone(), convert_schema(schema)))
+                            .collect();
+                        result.insert(key.clone(), Value::Object(converted));
+                    }
+                    "example" | "enum" | "default" | "required" => {
+                        result.insert(key.clone(), value.clone());
+                    }
+                    _ => {
+                        result.insert(key.clone(), convert_schema(value));
+                    }
+                }
+            }
+
+            // Swagger has a single `discriminator` property name
+            if let Some(Value::String(property_name)) = object.get("discriminator") {
+                result.insert(
+                    "discriminator".to_string(),
+                    json!({ "propertyName": property_name }),
+                );
+            }
+
+            Value::Object(result)
+        }
+        Value::Array(items) => Value::Array(items.iter().map(convert_schema).collect()),
+        other => other.clone(),
+    }
+}
+
+fn convert_security_scheme(scheme: &Value) -> Value {
+    match scheme.get("type").and_then(Value::as_str) {
+        Some("basic") => {
+            let mut result = json!({ "type": "http", "scheme": "basic" });
+            if let Some(description) = scheme.get("description") {
+                result["description"] = description.clone();
+            }
+            result
+        }
+        Some("oauth2") => {
+            let flow = match scheme.get("flow").and_then(Value::as_str) {
+                Some("implicit") => "implicit",
+                Some("password") => "password",
+                Some("application") => "clientCredentials",
+                _ => "authorizationCode",
+            };
+
+            let mut flow_object = Map::new();
+            for (from, to) in [
+                ("authorizationUrl", "authorizationUrl"),
+                ("tokenUrl", "tokenUrl"),
+            ] {
+                if let Some(value) = scheme.get(from) {
+                    flow_object.insert(to.to_string(), value.clone());
+                }
+            }
+            flow_object.insert(
+                "scopes".to_string(),
+                scheme.get("scopes").cloned().unwrap_or_else(|| json!({})),
+            );
+
+            let mut result = json!({ "type": "oauth2", "flows": { flow: flow_object } });
+            if let Some(description) = scheme.get("description") {
+                result["description"] = description.clone();
+            }
+            result
+        }
+        _ => scheme.clone(),
+    }
+}
+
+/// Rewrite a Swagger 2.0 local ref to its OpenAPI 3 components location
+fn convert_ref(reference: &str, is_body_parameter: bool) -> String {
+    if let Some(name) = reference.strip_prefix("#/definitions/") {
+        format!("#/components/schemas/{}", name)
+    } else if let Some(name) = reference.strip_prefix("#/parameters/") {
+        if is_body_parameter {
+            format!("#/components/requestBodies/{}", name)
+        } else {
+            format!("#/components/parameters/{}", name)
+        }
+    } else if let Some(name) = reference.strip_prefix("#/responses/") {
+        format!("#/components/responses/{}", name)
+    } else {
+        reference.to_string()
+    }
+}
+
+fn content(media_types: &[String], schema: &Value) -> Value {
+    Value::Object(
+        media_types
+            .iter()
+            .map(|media_type| (media_type.clone(), json!({ "schema": schema })))
+            .collect(),
+    )
+}
+
+fn media_types(declared: &[String], default: &[&str]) -> Vec<String> {
+    if declared.is_empty() {
+        default.iter().map(|t| t.to_string()).collect()
+    } else {
+        declared.to_vec()
+    }
+}
+
+fn string_list(value: Option<&Value>) -> Vec<String> {
+    array(value)
+        .iter()
+        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+        .collect()
+}
+
+fn array(value: Option<&Value>) -> &[Value] {
+    value
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
+
+fn as_object(value: &Value) -> &Map<String, Value> {
+    static EMPTY: std::sync::OnceLock<Map<String, Value>> = std::sync::OnceLock::new();
+    value
+        .as_object()
+        .unwrap_or_else(|| EMPTY.get_or_init(Map::new))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swagger_document() -> Value {
+        json!({
+            "swagger": "2.0",
+            "info": { "title": "Pets", "version": "1.0.0" },
+            "host": "api.example.com",
+            "basePath": "/v1",
+            "schemes": ["https"],
+            "consumes": ["application/json"],
+            "produces": ["application/json"],
+            "paths": {
+                "/pets/{id}": {
+                    "parameters": [
+                        { "name": "id", "in": "path", "required": true, "type": "integer" }
+                    ],
+                    "get": {
+                        "responses": {
+                            "200": { "description": "OK", "schema": { "$ref": "#/definitions/Pet" } }
+                        }
+                    },
+                    "put": {
+                        "parameters": [
+                            { "name": "body", "in": "body", "required": true, "schema": { "$ref": "#/definitions/Pet" } }
+                        ],
+                        "responses": { "204": { "description": "Updated" } }
+                    }
+                },
+                "/pets/{id}/photo": {
+                    "post": {
+                        "consumes": ["multipart/form-data"],
+                        "parameters": [
+                            { "name": "id", "in": "path", "required": true, "type": "integer" },
+                            { "name": "file", "in": "formData", "required": true, "type": "file" },
+                            { "name": "caption", "in": "formData", "type": "string" }
+                        ],
+                        "responses": { "201": { "description": "Created" } }
+                    }
+                }
+            },
+            "definitions": {
+                "Pet": {
+                    "type": "object",
+                    "required": ["name"],
+                    "properties": {
+                        "name": { "type": "string" },
+                        "tags": { "type": "array", "items": { "$ref": "#/definitions/Tag" } }
+                    }
+                },
+                "Tag": { "type": "string" }
+            }
+        })
+    }
+
+    #[test]
+    fn test_is_swagger_2() {
+        assert!(is_swagger_2(&swagger_document()));
+        assert!(!is_swagger_2(&json!({ "openapi": "3.0.0" })));
+        assert!(is_swagger_2(&json!({ "swagger": 2.0 })));
+        assert!(!is_swagger_2(&json!({ "swagger": 1.2 })));
+    }
+
+    #[test]
+    fn test_definitions_become_component_schemas() {
+        let converted = convert(&swagger_document());
+
+        assert_eq!(converted["openapi"], CONVERTED_VERSION);
+        assert_eq!(
+            converted["components"]["schemas"]["Pet"]["properties"]["tags"]["items"]["$ref"],
+            "#/components/schemas/Tag"
+        );
+        assert_eq!(converted["servers"][0]["url"], "https://api.example.com/v1");
+    }
+
+    #[test]
+    fn test_body_parameter_becomes_request_body() {
+        let converted = convert(&swagger_document());
+        let put = &converted["paths"]["/pets/{id}"]["put"];
+
+        assert_eq!(put["requestBody"]["required"], true);
+        assert_eq!(
+            put["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/Pet"
+        );
+        assert!(put.get("parameters").is_none());
+
+        let get = &converted["paths"]["/pets/{id}"]["get"];
+        assert_eq!(
+            get["responses"]["200"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/Pet"
+        );
+    }
+
+    #[test]
+    fn test_form_parameters_become_multipart_request_body() {
+        let converted = convert(&swagger_document());
+        let post = &converted["paths"]["/pets/{id}/photo"]["post"];
+        let schema = &post["requestBody"]["content"]["multipart/form-data"]["schema"];
+
+        assert_eq!(schema["properties"]["file"]["format"], "binary");
+        assert_eq!(schema["required"], json!(["file"]));
+        assert_eq!(post["parameters"][0]["schema"]["type"], "integer");
+        assert!(post["parameters"][0].get("type").is_none());
+    }
+
+    #[test]
+    fn test_converted_document_is_valid_openapi() {
+        let converted = convert(&swagger_document());
+        let spec: oas3::OpenApiV3Spec = serde_json::from_value(converted).unwrap();
+
+        let paths = spec.paths.unwrap();
+        assert!(paths["/pets/{id}"]
+            .put
+            .as_ref()
+            .unwrap()
+            .request_body
+            .is_some());
+        assert_eq!(paths["/pets/{id}"].parameters.len(), 1);
+    }
+}
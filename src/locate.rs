@@ -0,0 +1,394 @@
+//! JSON pointers and source positions of the nodes behind changes
+
+use crate::loader::{escape_pointer_segment, SourceMap, SOURCE_EXTENSION};
+use crate::rules::{Change, ChangeAnchor, ChangeLocation, NodeLocation};
+use oas3::spec::Spec;
+use serde_json::Value;
+
+/// Maximum number of `$ref`s followed in a row, in case of ref cycles
+const MAX_REF_HOPS: usize = 16;
+
+/// Locates changes in one version of a specification
+#[derive(Debug, Default)]
+pub struct SpecLocator {
+    document: Value,
+    source_map: Option<SourceMap>,
+}
+
+/// Node reached while walking the document
+#[derive(Clone)]
+struct Cursor<'a> {
+    pointer: String,
+    value: &'a Value,
+    /// Whether the node was bundled from another file, so it has no position in the source
+    external: bool,
+}
+
+impl SpecLocator {
+    pub fn new(spec: &Spec) -> Self {
+        Self {
+            document: serde_json::to_value(spec).unwrap_or_default(),
+            source_map: None,
+        }
+    }
+
+    /// Resolve line and column of nodes in the source the spec was loaded from
+    pub fn with_source_map(mut self, source_map: SourceMap) -> Self {
+        self.source_map = Some(source_map);
+        self
+    }
+
+    /// Node behind a change of the given schema, `None` if the schema doesn't exist.
+    ///
+    /// Nodes missing in this version (e.g. the base side of an added property) are
+    /// located at their closest existing parent.
+    pub fn schema_node(&self, schema_name: &str, change: &Change) -> Option<NodeLocation> {
+        let pointer = format!(
+            "/components/schemas/{}",
+            escape_pointer_segment(schema_name)
+        );
+        let mut cursor = self.cursor(pointer, false)?;
+
+        let path = change.path.as_deref().unwrap_or_default();
+        for segment in split_path(path) {
+            let node = self.resolve(cursor.clone());
+            let next = if segment == "{*}" {
+                self.child(&node, &["additionalProperties"])
+            } else if let Some(value) = discriminator_value(segment) {
+                self.subtype(&node, value)
+            } else {
+                self.child(&node, &["properties", segment])
+            };
+
+            match next {
+                Some(next) => cursor = next,
+                None => return Some(self.node(&cursor)),
+            }
+        }
+
+        if let Some(keyword) = anchor_keyword(&change.anchor) {
+            if let Some(child) = self.child(&self.resolve(cursor.clone()), &[keyword]) {
+                cursor = child;
+            }
+        }
+        Some(self.node(&cursor))
+    }
+
+    /// Node behind a change of the operation at `operation_pointer`, `None` if the
+    /// operation doesn't exist
+    pub fn route_node(&self, operation_pointer: &str, change: &Change) -> Option<NodeLocation> {
+        let operation = self.cursor(operation_pointer.to_string(), false)?;
+
+        let cursor = match &change.location {
+            None | Some(ChangeLocation::Operation) => None,
+            Some(ChangeLocation::Parameter { name, location }) => {
+                let path_item = &operation_pointer[..operation_pointer.rfind('/').unwrap_or(0)];
+                self.parameter(&operation, name, location).or_else(|| {
+                    let path_item = self.cursor(path_item.to_string(), false)?;
+                    self.parameter(&path_item, name, location)
+                })
+            }
+            Some(ChangeLocation::RequestBody { content_type }) => {
+                self.child(&operation, &["requestBody"]).map(|body| {
+                    let body = self.resolve(body.clone());
+                    self.child(&body, &["content", content_type])
+                        .unwrap_or(body)
+                })
+            }
+            Some(ChangeLocation::Response {
+                status_code,
+                content_type,
+            }) => self
+                .child(&operation, &["responses", status_code])
+                .map(|response| {
+                    let resolved = self.resolve(response.clone());
+                    content_type
+                        .as_ref()
+                        .and_then(|content_type| self.child(&resolved, &["content", content_type]))
+                        .unwrap_or(response)
+                }),
+        };
+
+        Some(self.node(cursor.as_ref().unwrap_or(&operation)))
+    }
+
+    fn node(&self, cursor: &Cursor) -> NodeLocation {
+        let source = match &self.source_map {
+            Some(source_map) if !cursor.external => source_map.locate(&cursor.pointer),
+            _ => None,
+        };
+
+        NodeLocation {
+            pointer: cursor.pointer.clone(),
+            source,
+        }
+    }
+
+    fn cursor(&self, pointer: String, external: bool) -> Option<Cursor<'_>> {
+        let value = self.document.pointer(&pointer)?;
+        Some(Cursor {
+            external: external || value.get(SOURCE_EXTENSION).is_some(),
+            pointer,
+            value,
+        })
+    }
+
+    fn child<'a>(&'a self, cursor: &Cursor<'a>, keys: &[&str]) -> Option<Cursor<'a>> {
+        let mut pointer = cursor.pointer.clone();
+        for key in keys {
+            pointer = format!("{}/{}", pointer, escape_pointer_segment(key));
+        }
+        self.cursor(pointer, cursor.external)
+    }
+
+    /// Follow local `$ref`s to the node they point at
+    fn resolve<'a>(&'a self, mut cursor: Cursor<'a>) -> Cursor<'a> {
+        for _ in 0..MAX_REF_HOPS {
+            let Some(target) = ref_pointer(cursor.value) else {
+                break;
+            };
+            match self.cursor(target.to_string(), false) {
+                Some(next) => cursor = next,
+                None => break,
+            }
+        }
+        cursor
+    }
+
+    /// Subtype of a schema with a discriminator, by the discriminator value
+    fn subtype<'a>(&'a self, schema: &Cursor<'a>, value: &str) -> Option<Cursor<'a>> {
+        let mapped = schema
+            .value
+            .pointer(&format!(
+                "/discriminator/mapping/{}",
+                escape_pointer_segment(value)
+            ))
+            .and_then(Value::as_str)
+            .and_then(|reference| reference.strip_prefix('#'));
+        if let Some(target) = mapped {
+            return self.cursor(target.to_string(), false);
+        }
+
+        // Implicit mapping by the name of the referenced schema
+        ["oneOf", "anyOf"]
+            .iter()
+            .filter_map(|keyword| schema.value.get(keyword)?.as_array())
+            .flatten()
+            .filter_map(ref_pointer)
+            .find(|target| target.rsplit('/').next() == Some(value))
+            .and_then(|target| self.cursor(target.to_string(), false))
+    }
+
+    /// Parameter with the given name and location among the parameters of an operation
+    /// or path item
+    fn parameter<'a>(
+        &'a self,
+        owner: &Cursor<'a>,
+        name: &str,
+        location: &str,
+    ) -> Option<Cursor<'a>> {
+        let count = owner.value.get("parameters")?.as_array()?.len();
+        (0..count)
+            .filter_map(|index| self.child(owner, &["parameters", &index.to_string()]))
+            .find(|parameter| {
+                let resolved = self.resolve(parameter.clone()).value;
+                resolved.get("name").and_then(Value::as_str) == Some(name)
+                    && resolved
+                        .get("in")
+                        .and_then(Value::as_str)
+                        .is_some_and(|value| value.eq_ignore_ascii_case(location))
+            })
+    }
+}
+
+/// Pointer of a local `$ref` of the node, without the leading `#`
+fn ref_pointer(value: &Value) -> Option<&str> {
+    value.get("$ref")?.as_str()?.strip_prefix('#')
+}
+
+/// Keyword of the schema node an anchor points into, e.g. `enum` for enum changes
+fn anchor_keyword(anchor: &ChangeAnchor) -> Option<&'static str> {
+    match anchor {
+        ChangeAnchor::PropertyType(_) => Some("type"),
+        ChangeAnchor::Required => Some("required"),
+        ChangeAnchor::EnumValues(_) => Some("enum"),
+        ChangeAnchor::Format(_) => Some("format"),
+        ChangeAnchor::Nullable(_) => Some("nullable"),
+        ChangeAnchor::ArrayItems(_) => Some("items"),
+        ChangeAnchor::Description(_) => Some("description"),
+        _ => None,
+    }
+}
+
+/// Value of a discriminator path segment, e.g. `dog` for `{petType=dog}`
+fn discriminator_value(segment: &str) -> Option<&str> {
+    let inner = segment.strip_prefix('{')?.strip_suffix('}')?;
+    inner.split_once('=').map(|(_, value)| value)
+}
+
+/// Split a property path into segments, keeping dots inside `{...}` segments
+fn split_path(path: &str) -> Vec<&str> {
+    let mut segments = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (index, c) in path.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            '.' if depth == 0 => {
+                segments.push(&path[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    if !path.is_empty() {
+        segments.push(&path[start..]);
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loader::{parse_spec, SpecFormat};
+    use crate::rules::RuleCategory;
+    use crate::ChangeLevel;
+    use std::path::Path;
+
+    const SPEC: &str = "\
+openapi: 3.0.0
+info:
+  title: Test
+  version: 1.0.0
+paths:
+  /pets/{id}:
+    parameters:
+    - name: id
+      in: path
+      required: true
+      schema:
+        type: string
+    get:
+      responses:
+        '200':
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Pet'
+components:
+  schemas:
+    Pet:
+      type: object
+      discriminator:
+        propertyName: petType
+      oneOf:
+      - $ref: '#/components/schemas/Dog'
+      properties:
+        owner:
+          $ref: '#/components/schemas/Owner'
+    Dog:
+      properties:
+        bark:
+          type: string
+          enum: [loud]
+    Owner:
+      properties:
+        name:
+          type: string
+";
+
+    fn locator() -> SpecLocator {
+        let (spec, _) = parse_spec(SPEC, Path::new("openapi.yaml")).unwrap();
+        SpecLocator::new(&spec).with_source_map(SourceMap::new(SPEC, SpecFormat::Yaml))
+    }
+
+    fn change(anchor: ChangeAnchor, location: Option<ChangeLocation>) -> Change {
+        Change {
+            rule: "Test".to_string(),
+            description: String::new(),
+            level: ChangeLevel::Change,
+            category: RuleCategory::Schema,
+            path: anchor.property_path().map(str::to_string),
+            anchor,
+            schema_name: None,
+            location,
+            old_value: None,
+            new_value: None,
+            base_node: None,
+            current_node: None,
+        }
+    }
+
+    fn line(node: Option<NodeLocation>) -> Option<usize> {
+        node.and_then(|node| node.source).map(|source| source.line)
+    }
+
+    #[test]
+    fn test_schema_nodes() {
+        let locator = locator();
+
+        let node = locator.schema_node(
+            "Pet",
+            &change(ChangeAnchor::Property("owner.name".into()), None),
+        );
+        assert_eq!(
+            node.as_ref().unwrap().pointer,
+            "/components/schemas/Owner/properties/name"
+        );
+        assert_eq!(line(node), Some(39));
+
+        let anchor = ChangeAnchor::EnumValues("{petType=Dog}.bark".into());
+        let node = locator.schema_node("Pet", &change(anchor, None));
+        assert_eq!(
+            node.as_ref().unwrap().pointer,
+            "/components/schemas/Dog/properties/bark/enum"
+        );
+        assert_eq!(line(node), Some(36));
+
+        // Missing properties are located at their parent
+        let node =
+            locator.schema_node("Pet", &change(ChangeAnchor::Property("color".into()), None));
+        assert_eq!(node.unwrap().pointer, "/components/schemas/Pet");
+
+        assert!(locator
+            .schema_node("Cat", &change(ChangeAnchor::Schema, None))
+            .is_none());
+    }
+
+    #[test]
+    fn test_route_nodes() {
+        let locator = locator();
+        let operation = "/paths/~1pets~1{id}/get";
+
+        let parameter = ChangeLocation::Parameter {
+            name: "id".to_string(),
+            location: "Path".to_string(),
+        };
+        let node = locator.route_node(operation, &change(ChangeAnchor::Route, Some(parameter)));
+        assert_eq!(
+            node.as_ref().unwrap().pointer,
+            "/paths/~1pets~1{id}/parameters/0"
+        );
+        assert_eq!(line(node), Some(8));
+
+        let response = ChangeLocation::Response {
+            status_code: "200".to_string(),
+            content_type: Some("application/json".to_string()),
+        };
+        let node = locator.route_node(operation, &change(ChangeAnchor::Route, Some(response)));
+        assert_eq!(line(node), Some(18));
+
+        assert!(locator
+            .route_node("/paths/~1pets/get", &change(ChangeAnchor::Route, None))
+            .is_none());
+    }
+
+    #[test]
+    fn test_split_path() {
+        assert_eq!(split_path(""), Vec::<&str>::new());
+        assert_eq!(split_path("a.{type=v1.2}.b"), vec!["a", "{type=v1.2}", "b"]);
+    }
+}
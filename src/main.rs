@@ -1,19 +1,113 @@
+use apidrift::filter::{filter_by_min_level, MatchFilter};
+use apidrift::loader::{self, SourceMap};
 use apidrift::matcher;
+use apidrift::render::changelog::ChangelogRenderer;
+use apidrift::render::github::GithubRenderer;
+use apidrift::render::gitlab::GitlabRenderer;
 use apidrift::render::html::HtmlRenderer;
+use apidrift::render::junit::JunitRenderer;
+use apidrift::render::sarif::SarifRenderer;
+use apidrift::render::text::TextRenderer;
+use apidrift::render::{Renderer, Report};
+use apidrift::source::{SpecSource, STDIO_NAME};
+use apidrift::ChangeLevel;
 use clap::{Parser, ValueEnum};
 use env_logger::Env;
 use oas3::OpenApiV3Spec;
 use std::env;
+use std::error::Error;
 use std::fs;
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set when the report itself goes to stdout, so progress messages are moved to stderr
+static STATUS_TO_STDERR: AtomicBool = AtomicBool::new(false);
+
+/// Print a progress message without mixing it into a report written to stdout
+macro_rules! status {
+    ($($arg:tt)*) => {
+        if STATUS_TO_STDERR.load(Ordering::Relaxed) {
+            eprintln!($($arg)*);
+        } else {
+            println!($($arg)*);
+        }
+    };
+}
 
 /// Available output formats
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
 pub enum OutputFormat {
     /// Generate an HTML report
     Html,
-    // Future: add more (e.g. Json, Markdown, etc.)
-    // Json,
+    /// Generate a SARIF 2.1.0 log for code scanning tools
+    Sarif,
+    /// Generate a JUnit XML test report, failing on breaking changes
+    Junit,
+    /// Print GitHub Actions workflow commands, annotating changes in pull requests
+    Github,
+    /// Generate a GitLab Code Quality report for the merge request widget
+    GitlabCodequality,
+    /// Print a colored tree of changes, written to stdout unless --output is given
+    Text,
+    /// Generate a Markdown changelog for API consumers
+    Changelog,
+}
+
+impl OutputFormat {
+    /// Name of the format in progress messages
+    fn label(&self) -> &'static str {
+        match self {
+            OutputFormat::Html => "HTML",
+            OutputFormat::Sarif => "SARIF",
+            OutputFormat::Junit => "JUnit XML",
+            OutputFormat::Github => "GitHub annotations",
+            OutputFormat::GitlabCodequality => "GitLab Code Quality",
+            OutputFormat::Text => "text",
+            OutputFormat::Changelog => "changelog",
+        }
+    }
+
+    fn renderer(
+        &self,
+        to_stdout: bool,
+        template_dir: Option<&Path>,
+    ) -> Result<Box<dyn Renderer>, Box<dyn Error>> {
+        Ok(match self {
+            OutputFormat::Html => match template_dir {
+                Some(dir) => Box::new(HtmlRenderer::new()?.with_template_dir(dir)?),
+                None => Box::new(HtmlRenderer::new()?),
+            },
+            OutputFormat::Sarif => Box::new(SarifRenderer),
+            OutputFormat::Junit => Box::new(JunitRenderer),
+            OutputFormat::Github => Box::new(GithubRenderer),
+            OutputFormat::GitlabCodequality => Box::new(GitlabRenderer),
+            OutputFormat::Text if to_stdout => Box::new(TextRenderer::for_stdout()),
+            OutputFormat::Text => Box::new(TextRenderer::new(false, None)),
+            OutputFormat::Changelog => Box::new(ChangelogRenderer),
+        })
+    }
+}
+
+/// Minimal change level to keep in the report
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
+pub enum MinLevel {
+    /// Keep all changes
+    Change,
+    /// Keep warnings and breaking changes
+    Warning,
+    /// Keep only breaking changes
+    Breaking,
+}
+
+impl From<MinLevel> for ChangeLevel {
+    fn from(level: MinLevel) -> Self {
+        match level {
+            MinLevel::Change => ChangeLevel::Change,
+            MinLevel::Warning => ChangeLevel::Warning,
+            MinLevel::Breaking => ChangeLevel::Breaking,
+        }
+    }
 }
 
 #[derive(Parser)]
@@ -22,22 +116,24 @@ pub enum OutputFormat {
 #[command(about = "Compare two OpenAPI specifications and generate a detailed diff report", long_about = None)]
 #[command(author = "sensiarion <izertmi@gmail.com>")]
 struct Cli {
-    /// Path to the base OpenAPI specification file (JSON or YAML format)
+    /// Path to the base OpenAPI specification file (JSON or YAML format).
+    /// Use 'git:REV:path/to/openapi.yaml' to read it from the local git repository
     #[arg(value_name = "BASE_SPEC")]
-    base_spec: PathBuf,
+    base_spec: SpecSource,
 
-    /// Path to the current OpenAPI specification file (JSON or YAML format)
+    /// Path to the current OpenAPI specification file (JSON or YAML format).
+    /// Use 'git:REV:path/to/openapi.yaml' to read it from the local git repository
     #[arg(value_name = "CURRENT_SPEC")]
-    current_spec: PathBuf,
+    current_spec: SpecSource,
 
-    /// Output HTML report file path
-    #[arg(
-        short = 'o',
-        long = "output",
-        value_name = "FILE",
-        default_value = "apidrift_report.html"
-    )]
-    output: PathBuf,
+    /// Read the base specification from this git revision (e.g. 'main')
+    #[arg(long = "base-rev", value_name = "REV")]
+    base_rev: Option<String>,
+
+    /// Output report file path [default: apidrift_report.<format extension>, stdout for text and github].
+    /// Use '-' to write the report to stdout
+    #[arg(short = 'o', long = "output", value_name = "FILE")]
+    output: Option<PathBuf>,
 
     /// Open the report in browser after generation
     #[arg(long = "open")]
@@ -64,59 +160,60 @@ struct Cli {
         value_name = "FORMAT"
     )]
     pub format: OutputFormat,
-}
 
-fn detect_format(path: &Path) -> Result<&'static str, String> {
-    let extension = path
-        .extension()
-        .and_then(|s| s.to_str())
-        .ok_or_else(|| format!("Unable to determine file format for: {}", path.display()))?;
-
-    match extension.to_lowercase().as_str() {
-        "json" => Ok("json"),
-        "yaml" | "yml" => Ok("yaml"),
-        _ => Err(format!(
-            "Unsupported file format '{}'. Supported formats: json, yaml, yml",
-            extension
-        )),
-    }
+    /// Directory with custom Tera templates for the HTML report. Templates missing from it
+    /// fall back to the built-in ones
+    #[arg(long = "template-dir", value_name = "DIR")]
+    template_dir: Option<PathBuf>,
+
+    /// Only compare routes whose path matches this glob (e.g. '/api/v2/**'). Can be repeated
+    #[arg(long = "include-path", value_name = "GLOB")]
+    include_paths: Vec<String>,
+
+    /// Skip routes whose path matches this glob (e.g. '/internal/**'). Can be repeated
+    #[arg(long = "exclude-path", value_name = "GLOB")]
+    exclude_paths: Vec<String>,
+
+    /// Skip schemas whose name matches this glob (e.g. 'Internal*'). Can be repeated
+    #[arg(long = "exclude-schema", value_name = "GLOB")]
+    exclude_schemas: Vec<String>,
+
+    /// Only report changes of this level or higher
+    #[arg(
+        long = "min-level",
+        value_enum,
+        default_value = "change",
+        value_name = "LEVEL"
+    )]
+    min_level: MinLevel,
 }
 
-fn parse_openapi(path: &Path, verbose: bool) -> Result<OpenApiV3Spec, String> {
+/// Load a spec, along with the positions of its nodes in the source file
+fn parse_openapi(source: &SpecSource, verbose: bool) -> Result<(OpenApiV3Spec, SourceMap), String> {
     if verbose {
-        println!("📖 Reading OpenAPI spec from: {}", path.display());
+        status!("📖 Reading OpenAPI spec from: {}", source);
     }
 
-    let openapi_content = fs::read_to_string(path)
-        .map_err(|err| format!("Failed to read file \"{}\". Error: {}", path.display(), err))?;
+    let openapi_content = source.read_to_string()?;
 
-    let format = detect_format(path)?;
+    let (spec, format) = loader::parse_spec_bundled(&openapi_content, source.path(), |file| {
+        let referenced = source.sibling(file);
+        if verbose {
+            status!("   Reading referenced file: {}", referenced);
+        }
+        referenced.read_to_string()
+    })
+    .map_err(|err| format!("Failed to load \"{}\". {}", source, err))?;
 
     if verbose {
-        println!("   Detected format: {}", format.to_uppercase());
+        status!("   Detected format: {}", format);
     }
 
-    match format {
-        "json" => oas3::from_json(openapi_content).map_err(|err| {
-            format!(
-                "Invalid OpenAPI JSON schema in \"{}\". Error: {}",
-                path.display(),
-                err
-            )
-        }),
-        "yaml" => oas3::from_yaml(openapi_content).map_err(|err| {
-            format!(
-                "Invalid OpenAPI YAML schema in \"{}\". Error: {}",
-                path.display(),
-                err
-            )
-        }),
-        _ => unreachable!(),
-    }
+    Ok((spec, SourceMap::new(&openapi_content, format)))
 }
 
 fn open_in_browser(path: &Path, use_chrome: bool) {
-    println!("🌐 Opening report in browser...");
+    status!("🌐 Opening report in browser...");
 
     // Try Chrome if requested
     if use_chrome {
@@ -145,22 +242,22 @@ fn open_in_browser(path: &Path, use_chrome: bool) {
 
         match chrome_result {
             Ok(_) => {
-                println!("✨ Opened in Chrome!");
+                status!("✨ Opened in Chrome!");
                 return;
             }
             Err(e) => {
                 eprintln!("⚠️  Failed to open Chrome: {}", e);
-                println!("Falling back to default browser...");
+                status!("Falling back to default browser...");
             }
         }
     }
 
     // Try default browser using the 'open' crate
     if open::that(path).is_ok() {
-        println!("✨ Opened in default browser!");
+        status!("✨ Opened in default browser!");
     } else {
         eprintln!("⚠️  Failed to open browser automatically");
-        println!("Please open the file manually: {}", path.display());
+        status!("Please open the file manually: {}", path.display());
     }
 }
 
@@ -174,16 +271,52 @@ fn main() {
     };
     env_logger::init_from_env(Env::default().default_filter_or(log_level));
 
-    println!(
+    let write_to_stdout = match cli.output.as_deref() {
+        Some(path) => path == Path::new(STDIO_NAME),
+        None => matches!(cli.format, OutputFormat::Text | OutputFormat::Github),
+    };
+    STATUS_TO_STDERR.store(write_to_stdout, Ordering::Relaxed);
+
+    status!(
         "🔍 ApiDrift - OpenAPI Diff Tool v{}\n",
         env!("CARGO_PKG_VERSION")
     );
 
+    if cli.template_dir.is_some() && cli.format != OutputFormat::Html {
+        eprintln!("❌ Error: --template-dir can be used only with the HTML format");
+        std::process::exit(1);
+    }
+
+    let filter =
+        match MatchFilter::new(&cli.include_paths, &cli.exclude_paths, &cli.exclude_schemas) {
+            Ok(filter) => filter,
+            Err(err) => {
+                eprintln!("❌ Error: {}", err);
+                std::process::exit(1);
+            }
+        };
+
+    let base_spec = match &cli.base_rev {
+        Some(rev) => match &cli.base_spec {
+            SpecSource::File(_) => cli.base_spec.at_revision(rev),
+            SpecSource::Git { .. } | SpecSource::Stdin => {
+                eprintln!("❌ Error: --base-rev can be used only with a base specification file");
+                std::process::exit(1);
+            }
+        },
+        None => cli.base_spec.clone(),
+    };
+
+    if base_spec == SpecSource::Stdin && cli.current_spec == SpecSource::Stdin {
+        eprintln!("❌ Error: Only one specification can be read from stdin");
+        std::process::exit(1);
+    }
+
     // Validate input files exist
-    if !cli.base_spec.exists() {
+    if !base_spec.exists() {
         eprintln!(
             "❌ Error: Base specification file does not exist: {}",
-            cli.base_spec.display()
+            base_spec
         );
         std::process::exit(1);
     }
@@ -191,17 +324,17 @@ fn main() {
     if !cli.current_spec.exists() {
         eprintln!(
             "❌ Error: Current specification file does not exist: {}",
-            cli.current_spec.display()
+            cli.current_spec
         );
         std::process::exit(1);
     }
 
     // Parse OpenAPI specifications
     if cli.verbose {
-        println!("🔄 Parsing OpenAPI specifications...\n");
+        status!("🔄 Parsing OpenAPI specifications...\n");
     }
 
-    let base = match parse_openapi(&cli.base_spec, cli.verbose) {
+    let (base, base_source_map) = match parse_openapi(&base_spec, cli.verbose) {
         Ok(spec) => spec,
         Err(err) => {
             eprintln!("❌ Error parsing base specification: {}", err);
@@ -209,7 +342,7 @@ fn main() {
         }
     };
 
-    let current = match parse_openapi(&cli.current_spec, cli.verbose) {
+    let (current, current_source_map) = match parse_openapi(&cli.current_spec, cli.verbose) {
         Ok(spec) => spec,
         Err(err) => {
             eprintln!("❌ Error parsing current specification: {}", err);
@@ -218,7 +351,7 @@ fn main() {
     };
 
     if cli.verbose {
-        println!("✅ Successfully parsed both specifications\n");
+        status!("✅ Successfully parsed both specifications\n");
     }
 
     // Get schemas from both versions
@@ -243,72 +376,129 @@ fn main() {
 
     // Create schema matcher and compare schemas
     let schema_matcher =
-        matcher::SchemaMatcher::new(base_schemas, current_schemas, &base, &current);
+        matcher::SchemaMatcher::new(base_schemas, current_schemas, &base, &current)
+            .with_filter(filter.clone())
+            .with_source_maps(base_source_map.clone(), current_source_map.clone());
     let schema_results = schema_matcher.match_schemas();
-    let full_schema_infos = schema_matcher.build_full_schema_infos(&schema_results);
 
-    // Create route matcher and compare routes
-    let route_matcher = matcher::RouteMatcher::new(&base, &current);
+    // Create route matcher and compare routes, against all schema changes
+    // since a change can be rated higher on a route than on its schema
+    let route_matcher = matcher::RouteMatcher::new(&base, &current)
+        .with_filter(filter)
+        .with_source_maps(base_source_map, current_source_map);
     let route_results = route_matcher.match_routes_with_schema_violations(&schema_results);
     let route_infos = route_matcher.get_all_routes_with_schemas();
+    let event_results = route_matcher.match_event_routes_with_schema_violations(&schema_results);
+
+    // Filter by level only once every change is rated
+    let min_level = ChangeLevel::from(cli.min_level);
+    let route_side_levels =
+        matcher::RouteSideLevels::new(route_results.iter().chain(&event_results));
+    let schema_results = filter_by_min_level(route_side_levels.rate(schema_results), &min_level);
+    let full_schema_infos = schema_matcher.build_full_schema_infos(&schema_results);
+    let route_results = filter_by_min_level(route_results, &min_level);
+    let event_results = filter_by_min_level(event_results, &min_level);
+    let event_infos = route_matcher.get_all_event_routes_with_schemas();
 
     // Display stats
-    println!("=== Schema Comparison Stats ===\n");
-    println!("  Base schemas:         {}", base_schemas.len());
-    println!("  Current schemas:      {}", current_schemas.len());
-    println!("  Schemas with changes: {}", schema_results.len());
-
-    println!("\n=== Route Comparison Stats ===\n");
-    println!("  Total routes:         {}", route_infos.len());
-    println!("  Routes with changes:  {}", route_results.len());
-
-    // Render to HTML
-    println!("\n📄 Generating HTML report...");
-    let renderer = match HtmlRenderer::new() {
-        Ok(r) => r,
+    status!("=== Schema Comparison Stats ===\n");
+    status!("  Base schemas:         {}", base_schemas.len());
+    status!("  Current schemas:      {}", current_schemas.len());
+    status!("  Schemas with changes: {}", schema_results.len());
+
+    status!("\n=== Route Comparison Stats ===\n");
+    status!("  Total routes:         {}", route_infos.len());
+    status!("  Routes with changes:  {}", route_results.len());
+
+    if !event_infos.is_empty() || !event_results.is_empty() {
+        status!("\n=== Webhook & Callback Comparison Stats ===\n");
+        status!("  Total webhooks/callbacks:   {}", event_infos.len());
+        status!("  With changes:               {}", event_results.len());
+    }
+
+    status!("\n📄 Generating {} report...", cli.format.label());
+    let renderer = match cli
+        .format
+        .renderer(write_to_stdout, cli.template_dir.as_deref())
+    {
+        Ok(renderer) => renderer,
         Err(err) => {
-            eprintln!("❌ Error: Failed to create HTML renderer: {}", err);
+            eprintln!(
+                "❌ Error: Failed to create {} renderer: {}",
+                cli.format.label(),
+                err
+            );
             std::process::exit(1);
         }
     };
 
-    let html_output = match renderer.render_with_routes(
-        &schema_results,
-        &route_results,
-        &route_infos,
-        &full_schema_infos,
-    ) {
-        Ok(html) => html,
+    let report = Report {
+        schema_results: &schema_results,
+        route_results: &route_results,
+        route_infos: &route_infos,
+        event_results: &event_results,
+        event_infos: &event_infos,
+        full_schema_infos: &full_schema_infos,
+        current_file: (cli.current_spec != SpecSource::Stdin).then(|| cli.current_spec.path()),
+        base_version: &base.info.version,
+        current_version: &current.info.version,
+    };
+    let output = match renderer.render(&report) {
+        Ok(output) => output,
         Err(err) => {
-            eprintln!("❌ Error: Failed to render HTML: {}", err);
+            eprintln!(
+                "❌ Error: Failed to render {} report: {}",
+                cli.format.label(),
+                err
+            );
             std::process::exit(1);
         }
     };
 
+    if write_to_stdout {
+        if let Err(err) = io::stdout().write_all(output.as_bytes()) {
+            eprintln!("❌ Error: Failed to write report to stdout: {}", err);
+            std::process::exit(1);
+        }
+
+        if cli.open {
+            status!("\n⚠️  Warning: --open flag is ignored when writing the report to stdout");
+        }
+
+        status!("\n✨ Done!");
+        return;
+    }
+
     // Write to file
-    if let Err(err) = fs::write(&cli.output, html_output) {
-        eprintln!("❌ Error: Failed to write HTML file: {}", err);
+    let output_path = cli
+        .output
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(format!("apidrift_report.{}", renderer.file_extension())));
+    if let Err(err) = fs::write(&output_path, output) {
+        eprintln!("❌ Error: Failed to write report file: {}", err);
         std::process::exit(1);
     }
 
     let absolute_path =
-        match std::env::current_dir().and_then(|cwd| cwd.join(&cli.output).canonicalize()) {
+        match std::env::current_dir().and_then(|cwd| cwd.join(&output_path).canonicalize()) {
             Ok(path) => path,
-            Err(_) => cli.output.clone(),
+            Err(_) => output_path.clone(),
         };
 
-    println!("✅ Report generated: {}", absolute_path.display());
+    status!("✅ Report generated: {}", absolute_path.display());
 
     // Validate flag combination
     if cli.chrome && !cli.open {
-        println!("\n⚠️  Warning: --chrome flag requires --open flag to take effect");
+        status!("\n⚠️  Warning: --chrome flag requires --open flag to take effect");
     }
 
     // Open in browser if --open flag is set
-    if cli.open {
-        println!();
+    if cli.open && cli.format != OutputFormat::Html {
+        status!("\n⚠️  Warning: --open flag is supported only for HTML reports");
+    } else if cli.open {
+        status!();
         open_in_browser(&absolute_path, cli.chrome);
     }
 
-    println!("\n✨ Done!");
+    status!("\n✨ Done!");
 }
@@ -1,9 +1,21 @@
+use crate::filter::MatchFilter;
+use crate::loader::{escape_pointer_segment, SourceMap};
+use crate::locate::SpecLocator;
 use crate::rules::route::*;
 use crate::rules::schema::*;
-use crate::rules::{MatchResult, RuleViolation};
+use crate::rules::type_name::type_name_of;
+use crate::rules::{
+    Change, ChangeLocation, MatchResult, NodeLocation, RouteDirection, RouteSide, RuleViolation,
+    Visibility,
+};
 use log::info;
-use oas3::spec::{ObjectOrReference, ObjectSchema, Operation, PathItem, Spec};
-use std::collections::{BTreeMap, HashSet};
+use oas3::spec::{ObjectOrReference, ObjectSchema, Operation, Parameter, PathItem, Schema, Spec};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// HTTP methods compared for every path item
+const ROUTE_METHODS: [&str; 8] = [
+    "get", "post", "put", "delete", "patch", "head", "options", "trace",
+];
 
 /// Schema matcher for comparing OpenAPI schemas between versions
 pub struct SchemaMatcher<'a> {
@@ -11,6 +23,9 @@ pub struct SchemaMatcher<'a> {
     current_schemas: &'a BTreeMap<String, ObjectOrReference<ObjectSchema>>,
     base_spec: &'a Spec,
     current_spec: &'a Spec,
+    filter: MatchFilter,
+    base_locator: SpecLocator,
+    current_locator: SpecLocator,
 }
 
 impl<'a> SchemaMatcher<'a> {
@@ -25,9 +40,25 @@ impl<'a> SchemaMatcher<'a> {
             current_schemas,
             base_spec,
             current_spec,
+            filter: MatchFilter::default(),
+            base_locator: SpecLocator::new(base_spec),
+            current_locator: SpecLocator::new(current_spec),
         }
     }
 
+    /// Skip schemas excluded by the given filter
+    pub fn with_filter(mut self, filter: MatchFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Locate changes in the source files the specs were loaded from
+    pub fn with_source_maps(mut self, base: SourceMap, current: SourceMap) -> Self {
+        self.base_locator = self.base_locator.with_source_map(base);
+        self.current_locator = self.current_locator.with_source_map(current);
+        self
+    }
+
     /// Match schemas between base and current versions
     pub fn match_schemas(&self) -> Vec<MatchResult> {
         let mut results = Vec::new();
@@ -36,12 +67,24 @@ impl<'a> SchemaMatcher<'a> {
         let mut all_schema_names: std::collections::HashSet<String> =
             self.base_schemas.keys().cloned().collect();
         all_schema_names.extend(self.current_schemas.keys().cloned());
+        all_schema_names.retain(|name| self.filter.is_schema_included(name));
 
         for schema_name in all_schema_names {
             let base_schema = self.base_schemas.get(&schema_name);
             let current_schema = self.current_schemas.get(&schema_name);
 
-            let violations = self.compare_schemas(&schema_name, base_schema, current_schema);
+            let violations = self
+                .compare_schemas(&schema_name, base_schema, current_schema)
+                .into_iter()
+                .map(|violation| {
+                    let change = violation.change();
+                    LocatedViolation::wrap(
+                        violation,
+                        self.base_locator.schema_node(&schema_name, &change),
+                        self.current_locator.schema_node(&schema_name, &change),
+                    )
+                })
+                .collect::<Vec<_>>();
 
             if !violations.is_empty() {
                 results.push(MatchResult::new(schema_name, violations));
@@ -94,7 +137,10 @@ impl<'a> SchemaMatcher<'a> {
 
         for violation in violations {
             let anchor = violation.context();
+            let change = violation.change();
             let violation_info = ViolationInfo {
+                id: change.fingerprint(schema_name),
+                node: change.node().cloned(),
                 rule_name: violation.name().to_string(),
                 description: violation.description(),
                 change_level: match violation.change_level() {
@@ -113,7 +159,7 @@ impl<'a> SchemaMatcher<'a> {
                     if let Some(prop_path) = anchor.property_path() {
                         property_violations
                             .entry(prop_path.to_string())
-                            .or_insert_with(Vec::new)
+                            .or_default()
                             .push(violation_info);
                     }
                 }
@@ -126,13 +172,10 @@ impl<'a> SchemaMatcher<'a> {
 
         for (prop_name, prop_ref) in &schema.properties {
             let prop_schema = self.resolve_schema_ref(prop_ref, self.current_spec);
-            let property_type =
-                prop_schema.and_then(|s| s.schema_type.as_ref().map(|t| format!("{:?}", t)));
+            let property_type = Some(type_name_of(prop_ref));
             let format = prop_schema.and_then(|s| s.format.clone());
             let description = prop_schema.and_then(|s| s.description.clone());
-            let nullable = prop_schema
-                .map(|s| s.is_nullable().unwrap_or(false))
-                .unwrap_or(false);
+            let nullable = prop_schema.map(is_nullable).unwrap_or(false);
             let enum_values = prop_schema
                 .map(|s| s.enum_values.clone())
                 .unwrap_or_default();
@@ -169,9 +212,17 @@ impl<'a> SchemaMatcher<'a> {
             ChangeLevel::Change => ("Change".to_string(), "change".to_string()),
         };
 
+        // Extension keys are stored without the "x-" prefix
+        let source = schema
+            .extensions
+            .get(crate::loader::SOURCE_EXTENSION.trim_start_matches("x-"))
+            .and_then(|value| value.as_str())
+            .map(|value| value.to_string());
+
         FullSchemaInfo {
             name: schema_name.to_string(),
             description: schema.description.clone(),
+            source,
             properties,
             schema_level_violations,
             change_level: change_level_str,
@@ -192,7 +243,9 @@ impl<'a> SchemaMatcher<'a> {
         let base_schema = base.and_then(|b| self.resolve_schema_ref(b, self.base_spec));
         let current_schema = current.and_then(|c| self.resolve_schema_ref(c, self.current_spec));
 
-        if base.is_some() && current.is_some() && base == current {
+        // Unchanged polymorphic schemas still need their subtypes compared
+        let polymorphic = current_schema.is_some_and(|schema| schema.discriminator.is_some());
+        if base.is_some() && current.is_some() && base == current && !polymorphic {
             return violations;
         }
 
@@ -253,6 +306,15 @@ impl<'a> SchemaMatcher<'a> {
         }
     }
 
+    /// Reference to a schema in `components.schemas`
+    fn component_ref(schema_name: &str) -> ObjectOrReference<ObjectSchema> {
+        ObjectOrReference::Ref {
+            ref_path: format!("#/components/schemas/{}", schema_name),
+            summary: None,
+            description: None,
+        }
+    }
+
     /// Detect all schema-level rule violations using the SchemaRule trait
     fn detect_schema_rule_violations<T: crate::rules::schema::SchemaRule + 'static>(
         &self,
@@ -275,7 +337,50 @@ impl<'a> SchemaMatcher<'a> {
         base: &ObjectOrReference<ObjectSchema>,
         current: &ObjectOrReference<ObjectSchema>,
     ) -> Vec<RuleViolation> {
-        self.compare_schema_details_with_depth(schema_name, property_path, base, current, 0)
+        self.compare_schema_details_with_depth(
+            schema_name,
+            property_path,
+            base,
+            current,
+            0,
+            Visibility::Both,
+        )
+    }
+
+    /// Detect rule violations about a named property, scoped to that property's visibility
+    fn detect_property_rule_violations<T: crate::rules::schema::SchemaRule + 'static>(
+        &self,
+        schema_name: &str,
+        property_path: &str,
+        base: &ObjectSchema,
+        current: &ObjectSchema,
+        property_name: fn(&T) -> &str,
+    ) -> Vec<RuleViolation> {
+        T::detect(schema_name, property_path, Some(base), Some(current))
+            .into_iter()
+            .map(|rule| {
+                let visibility = self.property_visibility(base, current, property_name(&rule));
+                ScopedViolation::wrap(RuleViolation::new(Box::new(rule)), visibility)
+            })
+            .collect()
+    }
+
+    /// Visibility of a property in both versions of its parent schema
+    fn property_visibility(
+        &self,
+        base: &ObjectSchema,
+        current: &ObjectSchema,
+        property_name: &str,
+    ) -> Visibility {
+        let base_prop = base
+            .properties
+            .get(property_name)
+            .and_then(|prop| self.resolve_schema_ref(prop, self.base_spec));
+        let current_prop = current
+            .properties
+            .get(property_name)
+            .and_then(|prop| self.resolve_schema_ref(prop, self.current_spec));
+        Visibility::shared(base_prop, current_prop)
     }
 
     /// Compare detailed schema properties with recursion depth tracking
@@ -286,6 +391,7 @@ impl<'a> SchemaMatcher<'a> {
         base: &ObjectOrReference<ObjectSchema>,
         current: &ObjectOrReference<ObjectSchema>,
         depth: usize,
+        visibility: Visibility,
     ) -> Vec<RuleViolation> {
         const MAX_DEPTH: usize = 30; // Prevent infinite recursion
         let mut violations = Vec::new();
@@ -306,6 +412,9 @@ impl<'a> SchemaMatcher<'a> {
             None => return violations, // Skip if we can't resolve the reference
         };
 
+        // readOnly/writeOnly apply to everything nested in the schema
+        let visibility = visibility.or(Visibility::shared(Some(base_schema), Some(current_schema)));
+
         // Use SchemaRule trait for detection
         violations.extend(self.detect_schema_rule_violations::<TypeChangedRule>(
             schema_name,
@@ -314,28 +423,29 @@ impl<'a> SchemaMatcher<'a> {
             Some(current_schema),
         ));
 
-        violations.extend(
-            self.detect_schema_rule_violations::<RequiredPropertyAddedRule>(
-                schema_name,
-                property_path,
-                Some(base_schema),
-                Some(current_schema),
-            ),
-        );
+        violations.extend(self.detect_property_rule_violations(
+            schema_name,
+            property_path,
+            base_schema,
+            current_schema,
+            |rule: &RequiredPropertyAddedRule| &rule.property_name,
+        ));
 
         // Use SchemaRule trait for property-level detection
-        violations.extend(self.detect_schema_rule_violations::<PropertyAddedRule>(
+        violations.extend(self.detect_property_rule_violations(
             schema_name,
             property_path,
-            Some(base_schema),
-            Some(current_schema),
+            base_schema,
+            current_schema,
+            |rule: &PropertyAddedRule| &rule.property_name,
         ));
 
-        violations.extend(self.detect_schema_rule_violations::<PropertyRemovedRule>(
+        violations.extend(self.detect_property_rule_violations(
             schema_name,
             property_path,
-            Some(base_schema),
-            Some(current_schema),
+            base_schema,
+            current_schema,
+            |rule: &PropertyRemovedRule| &rule.property_name,
         ));
 
         // Detect properties that were removed from required array but still exist as optional
@@ -348,13 +458,17 @@ impl<'a> SchemaMatcher<'a> {
         for prop in base_required.difference(&current_required) {
             // Only if the property still exists (made optional rather than removed)
             if current_props_keys.contains(prop) {
-                violations.push(RuleViolation::new(Box::new(PropertyRemovedRule {
+                let rule = PropertyRemovedRule {
                     schema_name: schema_name.to_string(),
                     property_path: property_path.to_string(),
                     property_name: (*prop).clone(),
                     was_required: true,
                     totally_removed: false, // Property still exists, just made optional
-                })));
+                };
+                violations.push(ScopedViolation::wrap(
+                    RuleViolation::new(Box::new(rule)),
+                    self.property_visibility(base_schema, current_schema, prop),
+                ));
             }
         }
 
@@ -376,11 +490,92 @@ impl<'a> SchemaMatcher<'a> {
                     base_prop,
                     current_prop,
                     depth + 1,
+                    visibility,
                 );
                 violations.extend(prop_violations);
             }
         }
 
+        // Map values (`additionalProperties` schemas) are compared like a property named `{*}`
+        if let (Some(Schema::Object(base_values)), Some(Schema::Object(current_values))) = (
+            &base_schema.additional_properties,
+            &current_schema.additional_properties,
+        ) {
+            let values_path = if property_path.is_empty() {
+                "{*}".to_string()
+            } else {
+                format!("{}.{{*}}", property_path)
+            };
+            violations.extend(self.compare_schema_details_with_depth(
+                schema_name,
+                &values_path,
+                base_values,
+                current_values,
+                depth + 1,
+                visibility,
+            ));
+        }
+
+        // Subtypes are compared under their discriminator value, e.g. `{petType=dog}`
+        if let Some(discriminator) = &current_schema.discriminator {
+            let base_mapping = discriminator_mapping(base_schema);
+            for (value, current_target) in discriminator_mapping(current_schema) {
+                let Some(base_target) = base_mapping.get(&value) else {
+                    continue;
+                };
+                let subtype_segment = format!("{{{}={}}}", discriminator.property_name, value);
+                let subtype_path = if property_path.is_empty() {
+                    subtype_segment
+                } else {
+                    format!("{}.{}", property_path, subtype_segment)
+                };
+                violations.extend(self.compare_schema_details_with_depth(
+                    schema_name,
+                    &subtype_path,
+                    &Self::component_ref(base_target),
+                    &Self::component_ref(&current_target),
+                    depth + 1,
+                    visibility,
+                ));
+            }
+        }
+
+        violations.extend(
+            self.detect_schema_rule_violations::<DiscriminatorPropertyChangedRule>(
+                schema_name,
+                property_path,
+                Some(base_schema),
+                Some(current_schema),
+            ),
+        );
+
+        violations.extend(
+            self.detect_schema_rule_violations::<DiscriminatorMappingChangedRule>(
+                schema_name,
+                property_path,
+                Some(base_schema),
+                Some(current_schema),
+            ),
+        );
+
+        violations.extend(
+            self.detect_schema_rule_violations::<AdditionalPropertiesClosedRule>(
+                schema_name,
+                property_path,
+                Some(base_schema),
+                Some(current_schema),
+            ),
+        );
+
+        violations.extend(
+            self.detect_schema_rule_violations::<AdditionalPropertiesChangedRule>(
+                schema_name,
+                property_path,
+                Some(base_schema),
+                Some(current_schema),
+            ),
+        );
+
         // Use SchemaRule trait for all other detections
         violations.extend(
             self.detect_schema_rule_violations::<DescriptionChangedRule>(
@@ -391,28 +586,99 @@ impl<'a> SchemaMatcher<'a> {
             ),
         );
 
-        violations.extend(self.detect_schema_rule_violations::<EnumValuesAddedRule>(
+        violations.extend(self.detect_schema_rule_violations::<EnumChangedRule>(
             schema_name,
             property_path,
             Some(base_schema),
             Some(current_schema),
         ));
 
-        violations.extend(self.detect_schema_rule_violations::<EnumValuesRemovedRule>(
+        violations.extend(self.detect_schema_rule_violations::<FormatChangedRule>(
             schema_name,
             property_path,
             Some(base_schema),
             Some(current_schema),
         ));
 
-        violations.extend(self.detect_schema_rule_violations::<FormatChangedRule>(
+        violations.extend(self.detect_schema_rule_violations::<NullableChangedRule>(
             schema_name,
             property_path,
             Some(base_schema),
             Some(current_schema),
         ));
 
-        violations.extend(self.detect_schema_rule_violations::<NullableChangedRule>(
+        violations.extend(self.detect_schema_rule_violations::<ConstChangedRule>(
+            schema_name,
+            property_path,
+            Some(base_schema),
+            Some(current_schema),
+        ));
+
+        violations.extend(
+            self.detect_schema_rule_violations::<PrefixItemsChangedRule>(
+                schema_name,
+                property_path,
+                Some(base_schema),
+                Some(current_schema),
+            ),
+        );
+
+        violations.extend(
+            self.detect_schema_rule_violations::<ConditionalSchemaChangedRule>(
+                schema_name,
+                property_path,
+                Some(base_schema),
+                Some(current_schema),
+            ),
+        );
+
+        violations.extend(
+            self.detect_schema_rule_violations::<DependentRequiredChangedRule>(
+                schema_name,
+                property_path,
+                Some(base_schema),
+                Some(current_schema),
+            ),
+        );
+
+        violations.extend(
+            self.detect_schema_rule_violations::<UnevaluatedPropertiesChangedRule>(
+                schema_name,
+                property_path,
+                Some(base_schema),
+                Some(current_schema),
+            ),
+        );
+
+        violations.extend(self.detect_schema_rule_violations::<DefsChangedRule>(
+            schema_name,
+            property_path,
+            Some(base_schema),
+            Some(current_schema),
+        ));
+
+        violations.extend(self.detect_schema_rule_violations::<DefaultChangedRule>(
+            schema_name,
+            property_path,
+            Some(base_schema),
+            Some(current_schema),
+        ));
+
+        violations.extend(self.detect_schema_rule_violations::<ExampleChangedRule>(
+            schema_name,
+            property_path,
+            Some(base_schema),
+            Some(current_schema),
+        ));
+
+        violations.extend(self.detect_schema_rule_violations::<ReadOnlyChangedRule>(
+            schema_name,
+            property_path,
+            Some(base_schema),
+            Some(current_schema),
+        ));
+
+        violations.extend(self.detect_schema_rule_violations::<WriteOnlyChangedRule>(
             schema_name,
             property_path,
             Some(base_schema),
@@ -423,6 +689,9 @@ impl<'a> SchemaMatcher<'a> {
         // TODO: Implement proper array items comparison
 
         violations
+            .into_iter()
+            .map(|violation| ScopedViolation::wrap(violation, visibility))
+            .collect()
     }
 }
 
@@ -430,6 +699,9 @@ impl<'a> SchemaMatcher<'a> {
 pub struct RouteMatcher<'a> {
     base_spec: &'a Spec,
     current_spec: &'a Spec,
+    filter: MatchFilter,
+    base_locator: SpecLocator,
+    current_locator: SpecLocator,
 }
 
 /// Represents route information with associated schemas
@@ -437,10 +709,76 @@ pub struct RouteMatcher<'a> {
 pub struct RouteInfo {
     pub path: String,
     pub method: String,
+    pub kind: RouteKind,
     pub request_schemas: Vec<SchemaReference>,
     pub response_schemas: Vec<SchemaReference>,
 }
 
+impl RouteInfo {
+    /// Name of the route, as used for its match result
+    pub fn name(&self) -> String {
+        self.kind.route_name(&self.method, &self.path)
+    }
+}
+
+/// Where a route is declared in the specification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteKind {
+    /// Operation under `paths`
+    Path,
+    /// Operation under `webhooks`, its path is the webhook name
+    Webhook,
+    /// Operation of a callback, its path is the callback URL expression
+    Callback {
+        /// Route declaring the callback (e.g. "POST /subscriptions")
+        operation: String,
+        /// Callback name
+        name: String,
+    },
+}
+
+impl RouteKind {
+    /// Who sends the requests of routes of this kind
+    pub fn direction(&self) -> RouteDirection {
+        match self {
+            RouteKind::Path => RouteDirection::Incoming,
+            RouteKind::Webhook | RouteKind::Callback { .. } => RouteDirection::Outgoing,
+        }
+    }
+
+    /// JSON pointer of the operation of a route of this kind
+    fn pointer(&self, method: &str, path: &str) -> String {
+        let path_item = match self {
+            RouteKind::Path => format!("/paths/{}", escape_pointer_segment(path)),
+            RouteKind::Webhook => format!("/webhooks/{}", escape_pointer_segment(path)),
+            RouteKind::Callback { operation, name } => {
+                let (operation_method, operation_path) =
+                    operation.split_once(' ').unwrap_or_default();
+                format!(
+                    "{}/callbacks/{}/{}",
+                    RouteKind::Path.pointer(&operation_method.to_lowercase(), operation_path),
+                    escape_pointer_segment(name),
+                    escape_pointer_segment(path)
+                )
+            }
+        };
+        format!("{}/{}", path_item, method)
+    }
+
+    fn route_name(&self, method: &str, path: &str) -> String {
+        match self {
+            RouteKind::Path | RouteKind::Webhook => format!("{} {}", method.to_uppercase(), path),
+            RouteKind::Callback { operation, name } => format!(
+                "{} → {} → {} {}",
+                operation,
+                name,
+                method.to_uppercase(),
+                path
+            ),
+        }
+    }
+}
+
 /// Reference to a schema used in a route
 #[derive(Debug, Clone)]
 pub struct SchemaReference {
@@ -460,9 +798,25 @@ impl<'a> RouteMatcher<'a> {
         Self {
             base_spec,
             current_spec,
+            filter: MatchFilter::default(),
+            base_locator: SpecLocator::new(base_spec),
+            current_locator: SpecLocator::new(current_spec),
         }
     }
 
+    /// Skip routes whose paths are excluded by the given filter
+    pub fn with_filter(mut self, filter: MatchFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Locate changes in the source files the specs were loaded from
+    pub fn with_source_maps(mut self, base: SourceMap, current: SourceMap) -> Self {
+        self.base_locator = self.base_locator.with_source_map(base);
+        self.current_locator = self.current_locator.with_source_map(current);
+        self
+    }
+
     /// Match routes between base and current versions
     pub fn match_routes(&self) -> Vec<MatchResult> {
         self.match_routes_with_schema_violations(&[])
@@ -487,53 +841,199 @@ impl<'a> RouteMatcher<'a> {
         if let Some(paths) = current_paths {
             all_paths.extend(paths.keys().cloned());
         }
+        all_paths.retain(|path| self.filter.is_path_included(path));
 
         for path in all_paths {
             let base_path_item = base_paths.and_then(|p| p.get(&path));
             let current_path_item = current_paths.and_then(|p| p.get(&path));
 
-            // Compare each HTTP method
-            let methods = vec!["get", "post", "put", "delete", "patch", "head", "options"];
+            results.extend(self.match_path_items(
+                &RouteKind::Path,
+                &path,
+                base_path_item,
+                current_path_item,
+                schema_results,
+            ));
+        }
 
-            for method in methods {
-                let base_op = base_path_item.and_then(|p| self.get_operation(p, method));
-                let current_op = current_path_item.and_then(|p| self.get_operation(p, method));
+        results
+    }
 
-                // Skip if both are None
-                if base_op.is_none() && current_op.is_none() {
-                    continue;
-                }
+    /// Match webhooks and callbacks between base and current versions.
+    ///
+    /// Both are requests sent by the API to consumers, so they are compared as separate
+    /// pseudo-routes with inverted direction. Path filters apply to callbacks by the path
+    /// of the operation declaring them.
+    pub fn match_event_routes_with_schema_violations(
+        &self,
+        schema_results: &[MatchResult],
+    ) -> Vec<MatchResult> {
+        let mut results = Vec::new();
 
-                // Check if operations are identical (but still process if schemas have changes)
-                let operations_identical = if base_op.is_some() && current_op.is_some() {
-                    base_op == current_op
-                } else {
-                    false
+        let mut webhook_names: Vec<&String> = self.base_spec.webhooks.keys().collect();
+        webhook_names.extend(self.current_spec.webhooks.keys());
+        webhook_names.sort();
+        webhook_names.dedup();
+
+        for name in webhook_names {
+            results.extend(self.match_path_items(
+                &RouteKind::Webhook,
+                name,
+                self.base_spec.webhooks.get(name),
+                self.current_spec.webhooks.get(name),
+                schema_results,
+            ));
+        }
+
+        for (operation, base_op, current_op) in self.operation_pairs() {
+            let empty = BTreeMap::new();
+            let base_callbacks = base_op.map(|op| &op.callbacks).unwrap_or(&empty);
+            let current_callbacks = current_op.map(|op| &op.callbacks).unwrap_or(&empty);
+
+            let mut callback_names: Vec<&String> = base_callbacks.keys().collect();
+            callback_names.extend(current_callbacks.keys());
+            callback_names.sort();
+            callback_names.dedup();
+
+            for name in callback_names {
+                let base_paths = base_callbacks.get(name).map(|c| &c.paths);
+                let current_paths = current_callbacks.get(name).map(|c| &c.paths);
+
+                let mut expressions: Vec<&String> =
+                    base_paths.into_iter().flat_map(|p| p.keys()).collect();
+                expressions.extend(current_paths.into_iter().flat_map(|p| p.keys()));
+                expressions.sort();
+                expressions.dedup();
+
+                let kind = RouteKind::Callback {
+                    operation: operation.clone(),
+                    name: name.clone(),
                 };
+                for expression in expressions {
+                    results.extend(self.match_path_items(
+                        &kind,
+                        expression,
+                        base_paths.and_then(|p| p.get(expression)),
+                        current_paths.and_then(|p| p.get(expression)),
+                        schema_results,
+                    ));
+                }
+            }
+        }
 
-                let mut violations = Vec::new();
+        results
+    }
 
-                // Only compare operations if they're not identical
-                if !operations_identical {
-                    info!("Route {} {} not same ", method, path);
-                    violations.extend(self.compare_operations(&path, method, base_op, current_op));
-                }
+    /// Operations present in either version, by route name ("POST /subscriptions")
+    fn operation_pairs(&self) -> Vec<(String, Option<&'a Operation>, Option<&'a Operation>)> {
+        let mut pairs = Vec::new();
+        let base_paths = self.base_spec.paths.as_ref();
+        let current_paths = self.current_spec.paths.as_ref();
 
-                // Always check for schema violations for this route's schemas
-                if let Some(current_op) = current_op {
-                    let route_schemas = self.extract_route_schemas(&path, method, current_op);
-                    violations.extend(
-                        self.get_schema_violations_for_route(&route_schemas, schema_results),
-                    );
-                }
+        let mut all_paths: Vec<&String> = base_paths.into_iter().flat_map(|p| p.keys()).collect();
+        all_paths.extend(current_paths.into_iter().flat_map(|p| p.keys()));
+        all_paths.sort();
+        all_paths.dedup();
+        all_paths.retain(|path| self.filter.is_path_included(path));
 
-                if !violations.is_empty() {
-                    let route_name = format!("{} {}", method.to_uppercase(), path);
-                    results.push(MatchResult::new(route_name, violations));
+        for path in all_paths {
+            let base_item = base_paths.and_then(|p| p.get(path));
+            let current_item = current_paths.and_then(|p| p.get(path));
+
+            for method in ROUTE_METHODS {
+                let base_op = base_item.and_then(|p| self.get_operation(p, method));
+                let current_op = current_item.and_then(|p| self.get_operation(p, method));
+
+                if base_op.is_some() || current_op.is_some() {
+                    let name = RouteKind::Path.route_name(method, path);
+                    pairs.push((name, base_op, current_op));
                 }
             }
         }
 
+        pairs
+    }
+
+    /// Compare all operations of a path item (or webhook/callback path item)
+    fn match_path_items(
+        &self,
+        kind: &RouteKind,
+        path: &str,
+        base_path_item: Option<&PathItem>,
+        current_path_item: Option<&PathItem>,
+        schema_results: &[MatchResult],
+    ) -> Vec<MatchResult> {
+        let mut results = Vec::new();
+        let direction = kind.direction();
+
+        for method in ROUTE_METHODS {
+            let base_op = base_path_item.and_then(|p| {
+                self.get_operation(p, method)
+                    .map(|op| Self::effective_operation(self.base_spec, p, op))
+            });
+            let current_op = current_path_item.and_then(|p| {
+                self.get_operation(p, method)
+                    .map(|op| Self::effective_operation(self.current_spec, p, op))
+            });
+            let base_op = base_op.as_ref();
+            let current_op = current_op.as_ref();
+
+            // Skip if both are None
+            if base_op.is_none() && current_op.is_none() {
+                continue;
+            }
+
+            // Check if operations are identical (but still process if schemas have changes)
+            let operations_identical = if base_op.is_some() && current_op.is_some() {
+                base_op == current_op
+            } else {
+                false
+            };
+
+            let mut violations = Vec::new();
+
+            // Only compare operations if they're not identical
+            if !operations_identical {
+                info!("Route {} {} not same ", method, path);
+                let pointer = kind.pointer(method, path);
+                violations.extend(
+                    self.compare_operations(path, method, base_op, current_op)
+                        .into_iter()
+                        .map(|violation| {
+                            let change = violation.change();
+                            LocatedViolation::wrap(
+                                LeveledViolation::directed(violation, direction),
+                                self.base_locator.route_node(&pointer, &change),
+                                self.current_locator.route_node(&pointer, &change),
+                            )
+                        }),
+                );
+            }
+
+            // Always check for schema violations for this route's schemas,
+            // removed routes are left without schemas
+            let mut route = match current_op {
+                Some(current_op) => self.extract_route_schemas(path, method, current_op),
+                None => RouteInfo {
+                    path: path.to_string(),
+                    method: method.to_string(),
+                    kind: RouteKind::Path,
+                    request_schemas: vec![],
+                    response_schemas: vec![],
+                },
+            };
+            route.kind = kind.clone();
+            violations.extend(self.get_schema_violations_for_route(
+                &route,
+                schema_results,
+                direction,
+            ));
+
+            if !violations.is_empty() {
+                results.push(MatchResult::new(route.name(), violations).with_route(route));
+            }
+        }
+
         results
     }
 
@@ -547,10 +1047,46 @@ impl<'a> RouteMatcher<'a> {
             "patch" => path_item.patch.as_ref(),
             "head" => path_item.head.as_ref(),
             "options" => path_item.options.as_ref(),
+            "trace" => path_item.trace.as_ref(),
             _ => None,
         }
     }
 
+    /// Operation with the path-level parameters it inherits merged into its own.
+    /// Operation-level parameters override path-level ones with the same name and location
+    fn effective_operation(spec: &Spec, path_item: &PathItem, operation: &Operation) -> Operation {
+        let mut operation = operation.clone();
+
+        let own_keys: HashSet<_> = operation
+            .parameters
+            .iter()
+            .map(|param| Self::parameter_key(spec, param))
+            .collect();
+        operation.parameters.extend(
+            path_item
+                .parameters
+                .iter()
+                .filter(|param| !own_keys.contains(&Self::parameter_key(spec, param)))
+                .cloned(),
+        );
+
+        operation
+    }
+
+    /// Identity of a parameter: name and location, resolving references in `spec`.
+    /// Unresolvable references are identified by their ref path
+    fn parameter_key(spec: &Spec, param: &ObjectOrReference<Parameter>) -> (String, String) {
+        match param {
+            ObjectOrReference::Object(param) => {
+                (param.name.clone(), format!("{:?}", param.location))
+            }
+            ObjectOrReference::Ref { ref_path, .. } => match param.resolve(spec) {
+                Ok(param) => (param.name, format!("{:?}", param.location)),
+                Err(_) => (ref_path.clone(), "$ref".to_string()),
+            },
+        }
+    }
+
     /// Compare two operations and detect rule violations
     fn compare_operations(
         &self,
@@ -584,6 +1120,12 @@ impl<'a> RouteMatcher<'a> {
                 ),
             );
 
+            violations.extend(
+                self.detect_route_rule_violations::<RouteDeprecatedRule>(
+                    path, method, base, current,
+                ),
+            );
+
             violations.extend(
                 self.detect_route_rule_violations::<RequiredParameterAddedRule>(
                     path, method, base, current,
@@ -648,18 +1190,21 @@ impl<'a> RouteMatcher<'a> {
         let mut request_schemas = Vec::new();
         let mut response_schemas = Vec::new();
 
-        // Extract request body schemas
-        if let Some(request_body) = &operation.request_body {
-            if let ObjectOrReference::Object(body) = request_body {
-                for (content_type, media_type) in &body.content {
-                    if let Some(schema) = &media_type.schema {
-                        if let Some(schema_name) = Self::extract_schema_name_static(schema) {
-                            request_schemas.push(SchemaReference {
-                                schema_name,
-                                content_type: content_type.clone(),
-                                location: SchemaLocation::RequestBody,
-                            });
-                        }
+        // Extract request body schemas, bodies and responses may be component refs
+        // (e.g. global body parameters of Swagger 2.0 specs)
+        if let Some(body) = operation
+            .request_body
+            .as_ref()
+            .and_then(|body| body.resolve(self.current_spec).ok())
+        {
+            for (content_type, media_type) in &body.content {
+                if let Some(schema) = &media_type.schema {
+                    if let Some(schema_name) = Self::extract_schema_name_static(schema) {
+                        request_schemas.push(SchemaReference {
+                            schema_name,
+                            content_type: content_type.clone(),
+                            location: SchemaLocation::RequestBody,
+                        });
                     }
                 }
             }
@@ -668,7 +1213,7 @@ impl<'a> RouteMatcher<'a> {
         // Extract response schemas
         if let Some(responses) = &operation.responses {
             for (status_code, response_ref) in responses {
-                if let ObjectOrReference::Object(response) = response_ref {
+                if let Ok(response) = response_ref.resolve(self.current_spec) {
                     for (content_type, media_type) in &response.content {
                         if let Some(schema) = &media_type.schema {
                             if let Some(schema_name) = Self::extract_schema_name_static(schema) {
@@ -687,6 +1232,7 @@ impl<'a> RouteMatcher<'a> {
         RouteInfo {
             path: path.to_string(),
             method: method.to_string(),
+            kind: RouteKind::Path,
             request_schemas,
             response_schemas,
         }
@@ -708,9 +1254,11 @@ impl<'a> RouteMatcher<'a> {
 
         if let Some(paths) = &self.current_spec.paths {
             for (path, path_item) in paths {
-                let methods = vec!["get", "post", "put", "delete", "patch", "head", "options"];
+                if !self.filter.is_path_included(path) {
+                    continue;
+                }
 
-                for method in methods {
+                for method in ROUTE_METHODS {
                     if let Some(operation) = self.get_operation(path_item, method) {
                         routes.push(self.extract_route_schemas(path, method, operation));
                     }
@@ -721,11 +1269,55 @@ impl<'a> RouteMatcher<'a> {
         routes
     }
 
+    /// Get all webhooks and callbacks with their schema information for the current spec
+    pub fn get_all_event_routes_with_schemas(&self) -> Vec<RouteInfo> {
+        let mut routes = Vec::new();
+
+        for (name, path_item) in &self.current_spec.webhooks {
+            self.push_event_routes(&mut routes, RouteKind::Webhook, name, path_item);
+        }
+
+        for (operation_name, _, current_op) in self.operation_pairs() {
+            let Some(operation) = current_op else {
+                continue;
+            };
+
+            for (name, callback) in &operation.callbacks {
+                for (expression, path_item) in &callback.paths {
+                    let kind = RouteKind::Callback {
+                        operation: operation_name.clone(),
+                        name: name.clone(),
+                    };
+                    self.push_event_routes(&mut routes, kind, expression, path_item);
+                }
+            }
+        }
+
+        routes
+    }
+
+    fn push_event_routes(
+        &self,
+        routes: &mut Vec<RouteInfo>,
+        kind: RouteKind,
+        path: &str,
+        path_item: &PathItem,
+    ) {
+        for method in ROUTE_METHODS {
+            if let Some(operation) = self.get_operation(path_item, method) {
+                let mut route = self.extract_route_schemas(path, method, operation);
+                route.kind = kind.clone();
+                routes.push(route);
+            }
+        }
+    }
+
     /// Get schema violations for schemas used in a route
     fn get_schema_violations_for_route(
         &self,
         route_schemas: &RouteInfo,
         schema_results: &[MatchResult],
+        direction: RouteDirection,
     ) -> Vec<RuleViolation> {
         let mut violations = Vec::new();
 
@@ -753,6 +1345,13 @@ impl<'a> RouteMatcher<'a> {
         for schema_ref in &route_schemas.request_schemas {
             if let Some(schema_violations) = schema_violations_map.get(&schema_ref.schema_name) {
                 for violation in schema_violations {
+                    // e.g. readOnly properties are never sent in requests
+                    if !violation
+                        .visibility()
+                        .applies_to(direction, RouteSide::Request)
+                    {
+                        continue;
+                    }
                     violations.push(RuleViolation::new(Box::new(
                         RequestSchemaViolationWrapper {
                             schema_name: schema_ref.schema_name.clone(),
@@ -760,9 +1359,14 @@ impl<'a> RouteMatcher<'a> {
                             violation: RuleViolation::new(Box::new(SchemaViolationInfo {
                                 name: violation.name().to_string(),
                                 description: violation.description(),
-                                change_level: violation.change_level(),
+                                change_level: direction.change_level(
+                                    RouteSide::Request,
+                                    violation.name(),
+                                    violation.change_level(),
+                                ),
                                 context: violation.context(),
                                 category: violation.category(),
+                                change: violation.change(),
                             })),
                         },
                     )));
@@ -774,6 +1378,12 @@ impl<'a> RouteMatcher<'a> {
         for schema_ref in &route_schemas.response_schemas {
             if let Some(schema_violations) = schema_violations_map.get(&schema_ref.schema_name) {
                 for violation in schema_violations {
+                    if !violation
+                        .visibility()
+                        .applies_to(direction, RouteSide::Response)
+                    {
+                        continue;
+                    }
                     violations.push(RuleViolation::new(Box::new(
                         ResponseSchemaViolationWrapper {
                             schema_name: schema_ref.schema_name.clone(),
@@ -785,9 +1395,14 @@ impl<'a> RouteMatcher<'a> {
                             violation: RuleViolation::new(Box::new(SchemaViolationInfo {
                                 name: violation.name().to_string(),
                                 description: violation.description(),
-                                change_level: violation.change_level(),
+                                change_level: direction.change_level(
+                                    RouteSide::Response,
+                                    violation.name(),
+                                    violation.change_level(),
+                                ),
                                 context: violation.context(),
                                 category: violation.category(),
+                                change: violation.change(),
                             })),
                         },
                     )));
@@ -799,6 +1414,73 @@ impl<'a> RouteMatcher<'a> {
     }
 }
 
+/// Highest level each schema change gets across the routes and sides using its schema
+///
+/// The same change can be harmless in a request and breaking in a response, so reports
+/// rate schema changes by their route-side copies rather than by the schema alone.
+#[derive(Debug, Default)]
+pub struct RouteSideLevels {
+    levels: HashMap<String, crate::ChangeLevel>,
+}
+
+impl RouteSideLevels {
+    pub fn new<'r>(route_results: impl IntoIterator<Item = &'r MatchResult>) -> Self {
+        let mut levels: HashMap<String, crate::ChangeLevel> = HashMap::new();
+        for violation in route_results
+            .into_iter()
+            .flat_map(|result| &result.violations)
+            .filter(|violation| {
+                violation.name() == "RequestSchemaViolation"
+                    || violation.name() == "ResponseSchemaViolation"
+            })
+        {
+            let change = violation.change();
+            let Some(schema_name) = change.schema_name.as_deref() else {
+                continue;
+            };
+            let level = levels
+                .entry(Self::key(schema_name, &change))
+                .or_insert(crate::ChangeLevel::Change);
+            if !level.is_at_least(&change.level) {
+                *level = change.level.clone();
+            }
+        }
+
+        Self { levels }
+    }
+
+    /// Level of a change of the given schema, raised to the highest level it gets on a route
+    pub fn level_of(&self, schema_name: &str, change: &Change) -> crate::ChangeLevel {
+        match self.levels.get(&Self::key(schema_name, change)) {
+            Some(level) if !change.level.is_at_least(level) => level.clone(),
+            _ => change.level.clone(),
+        }
+    }
+
+    /// Raise the violations of schema results to their route-side levels
+    pub fn rate(&self, schema_results: Vec<MatchResult>) -> Vec<MatchResult> {
+        schema_results
+            .into_iter()
+            .map(|result| {
+                let violations = result
+                    .violations
+                    .into_iter()
+                    .map(|violation| {
+                        let level = self.level_of(&result.name, &violation.change());
+                        LeveledViolation::rated(violation, level)
+                    })
+                    .collect();
+                MatchResult::new(result.name, violations)
+            })
+            .collect()
+    }
+
+    /// Identifies a schema change and its copies on routes, which differ in location
+    fn key(schema_name: &str, change: &Change) -> String {
+        serde_json::json!([schema_name, change.rule, change.anchor, change.path]).to_string()
+    }
+}
+
 /// Simple wrapper to store violation info without cloning RuleViolation
 #[derive(Debug)]
 struct SchemaViolationInfo {
@@ -807,6 +1489,7 @@ struct SchemaViolationInfo {
     change_level: crate::ChangeLevel,
     context: crate::rules::ChangeAnchor,
     category: crate::rules::RuleCategory,
+    change: Change,
 }
 
 impl crate::rules::Rule for SchemaViolationInfo {
@@ -829,6 +1512,221 @@ impl crate::rules::Rule for SchemaViolationInfo {
     fn category(&self) -> crate::rules::RuleCategory {
         self.category.clone()
     }
+
+    fn change(&self) -> Change {
+        Change {
+            level: self.change_level.clone(),
+            ..self.change.clone()
+        }
+    }
+}
+
+/// Violation with its change level adjusted to where it applies
+#[derive(Debug)]
+struct LeveledViolation {
+    violation: RuleViolation,
+    change_level: crate::ChangeLevel,
+}
+
+impl LeveledViolation {
+    /// Route violation at its level for the route direction
+    fn directed(violation: RuleViolation, direction: RouteDirection) -> RuleViolation {
+        if direction == RouteDirection::Incoming {
+            return violation;
+        }
+
+        let change_level = direction.change_level(
+            RouteSide::of(&violation.category()),
+            violation.name(),
+            violation.change_level(),
+        );
+        RuleViolation::new(Box::new(LeveledViolation {
+            violation,
+            change_level,
+        }))
+    }
+
+    /// Schema violation raised to the highest level it gets on the routes using the schema
+    fn rated(violation: RuleViolation, change_level: crate::ChangeLevel) -> RuleViolation {
+        if violation.change_level().is_at_least(&change_level) {
+            return violation;
+        }
+
+        RuleViolation::new(Box::new(LeveledViolation {
+            violation,
+            change_level,
+        }))
+    }
+}
+
+impl crate::rules::Rule for LeveledViolation {
+    fn name(&self) -> &str {
+        self.violation.name()
+    }
+
+    fn description(&self) -> String {
+        self.violation.description()
+    }
+
+    fn change_level(&self) -> crate::ChangeLevel {
+        self.change_level.clone()
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        self.violation.context()
+    }
+
+    fn category(&self) -> crate::rules::RuleCategory {
+        self.violation.category()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.violation.visibility()
+    }
+
+    fn change(&self) -> Change {
+        Change {
+            level: self.change_level.clone(),
+            ..self.violation.change()
+        }
+    }
+}
+
+/// Schema violation limited to readOnly or writeOnly properties
+#[derive(Debug)]
+struct ScopedViolation {
+    violation: RuleViolation,
+    visibility: Visibility,
+}
+
+impl ScopedViolation {
+    fn wrap(violation: RuleViolation, visibility: Visibility) -> RuleViolation {
+        // Keep the most specific visibility, e.g. of a readOnly property inside an object
+        if visibility == Visibility::Both || violation.visibility() != Visibility::Both {
+            return violation;
+        }
+
+        RuleViolation::new(Box::new(ScopedViolation {
+            violation,
+            visibility,
+        }))
+    }
+
+    /// Rule level, lowered to the highest level on the route sides the property appears on,
+    /// e.g. a readOnly property that became required only matters to readers
+    fn scoped_level(&self) -> crate::ChangeLevel {
+        let level = self.violation.change_level();
+        let highest = [RouteDirection::Incoming, RouteDirection::Outgoing]
+            .into_iter()
+            .flat_map(|direction| {
+                [RouteSide::Request, RouteSide::Response].map(|side| (direction, side))
+            })
+            .filter(|(direction, side)| self.visibility.applies_to(*direction, *side))
+            .map(|(direction, side)| {
+                direction.change_level(side, self.violation.name(), level.clone())
+            })
+            .reduce(|highest, level| {
+                if level.is_at_least(&highest) {
+                    level
+                } else {
+                    highest
+                }
+            });
+
+        match highest {
+            Some(highest) if !highest.is_at_least(&level) => highest,
+            _ => level,
+        }
+    }
+}
+
+impl crate::rules::Rule for ScopedViolation {
+    fn name(&self) -> &str {
+        self.violation.name()
+    }
+
+    fn description(&self) -> String {
+        self.violation.description()
+    }
+
+    fn change_level(&self) -> crate::ChangeLevel {
+        self.scoped_level()
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        self.violation.context()
+    }
+
+    fn category(&self) -> crate::rules::RuleCategory {
+        self.violation.category()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    fn change(&self) -> Change {
+        Change {
+            level: self.scoped_level(),
+            ..self.violation.change()
+        }
+    }
+}
+
+/// Violation with the nodes behind it in both versions of the spec
+#[derive(Debug)]
+struct LocatedViolation {
+    violation: RuleViolation,
+    base_node: Option<NodeLocation>,
+    current_node: Option<NodeLocation>,
+}
+
+impl LocatedViolation {
+    fn wrap(
+        violation: RuleViolation,
+        base_node: Option<NodeLocation>,
+        current_node: Option<NodeLocation>,
+    ) -> RuleViolation {
+        RuleViolation::new(Box::new(LocatedViolation {
+            violation,
+            base_node,
+            current_node,
+        }))
+    }
+}
+
+impl crate::rules::Rule for LocatedViolation {
+    fn name(&self) -> &str {
+        self.violation.name()
+    }
+
+    fn description(&self) -> String {
+        self.violation.description()
+    }
+
+    fn change_level(&self) -> crate::ChangeLevel {
+        self.violation.change_level()
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        self.violation.context()
+    }
+
+    fn category(&self) -> crate::rules::RuleCategory {
+        self.violation.category()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.violation.visibility()
+    }
+
+    fn change(&self) -> Change {
+        Change {
+            base_node: self.base_node.clone(),
+            current_node: self.current_node.clone(),
+            ..self.violation.change()
+        }
+    }
 }
 
 /// Wrapper to add schema context to schema violations for routes
@@ -864,6 +1762,20 @@ impl crate::rules::Rule for RequestSchemaViolationWrapper {
     fn category(&self) -> crate::rules::RuleCategory {
         crate::rules::RuleCategory::RequestBody
     }
+
+    /// The schema change, located in the request body
+    fn change(&self) -> Change {
+        Change {
+            description: self.description(),
+            level: self.change_level(),
+            category: self.category(),
+            schema_name: Some(self.schema_name.clone()),
+            ..self.violation.change()
+        }
+        .at(ChangeLocation::RequestBody {
+            content_type: self.content_type.clone(),
+        })
+    }
 }
 
 /// Wrapper to add schema context to schema violations for routes
@@ -901,6 +1813,21 @@ impl crate::rules::Rule for ResponseSchemaViolationWrapper {
     fn category(&self) -> crate::rules::RuleCategory {
         crate::rules::RuleCategory::Response
     }
+
+    /// The schema change, located in the response
+    fn change(&self) -> Change {
+        Change {
+            description: self.description(),
+            level: self.change_level(),
+            category: self.category(),
+            schema_name: Some(self.schema_name.clone()),
+            ..self.violation.change()
+        }
+        .at(ChangeLocation::Response {
+            status_code: self.status_code.clone(),
+            content_type: Some(self.content_type.clone()),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -946,4 +1873,45 @@ mod tests {
         };
         assert!(matches!(added_rule.change_level(), ChangeLevel::Change));
     }
+
+    #[test]
+    fn test_operation_overrides_referenced_path_level_parameter() {
+        let spec = crate::loader::parse_spec(
+            r#"
+openapi: 3.0.3
+info: {title: Test, version: "1"}
+paths:
+  /users:
+    parameters:
+      - $ref: '#/components/parameters/Limit'
+      - {name: offset, in: query, schema: {type: integer}}
+    get:
+      parameters:
+        - {name: limit, in: query, required: true, schema: {type: integer}}
+      responses:
+        "200": {description: Users}
+components:
+  parameters:
+    Limit: {name: limit, in: query, schema: {type: integer}}
+"#,
+            std::path::Path::new("openapi.yaml"),
+        )
+        .unwrap()
+        .0;
+        let path_item = &spec.paths.as_ref().unwrap()["/users"];
+
+        let operation =
+            RouteMatcher::effective_operation(&spec, path_item, path_item.get.as_ref().unwrap());
+
+        let keys: Vec<_> = operation
+            .parameters
+            .iter()
+            .map(|param| RouteMatcher::parameter_key(&spec, param).0)
+            .collect();
+        assert_eq!(keys, vec!["limit", "offset"]);
+        assert!(matches!(
+            operation.parameters[0],
+            ObjectOrReference::Object(_)
+        ));
+    }
 }
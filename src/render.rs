@@ -1,13 +1,200 @@
-use crate::rules::MatchResult;
+use crate::matcher::{RouteInfo, RouteSideLevels};
+use crate::rules::{Change, FullSchemaInfo, MatchResult};
 use std::error::Error;
+use std::path::Path;
 
+pub mod changelog;
+pub mod github;
+pub mod gitlab;
 pub mod html;
+pub mod junit;
+pub mod sarif;
+pub mod text;
+
+/// Results of a comparison, as passed to renderers
+pub struct Report<'a> {
+    pub schema_results: &'a [MatchResult],
+    pub route_results: &'a [MatchResult],
+    pub route_infos: &'a [RouteInfo],
+    pub event_results: &'a [MatchResult],
+    pub event_infos: &'a [RouteInfo],
+    pub full_schema_infos: &'a [FullSchemaInfo],
+    /// Current spec file as given on the command line, `None` when read from stdin
+    pub current_file: Option<&'a Path>,
+    /// `info.version` of the base spec
+    pub base_version: &'a str,
+    /// `info.version` of the current spec
+    pub current_version: &'a str,
+}
+
+/// A change with the schema or route it was reported for
+pub struct ReportedChange {
+    /// Schema or route name
+    pub subject: String,
+    /// Stable fingerprint, see [`Change::fingerprint`]
+    pub id: String,
+    pub change: Change,
+}
+
+impl Report<'_> {
+    /// Every change once: schema changes and route changes, without the schema changes
+    /// repeated for each route using the schema. Schema changes are reported at the highest
+    /// level they get on those routes. Sorted by schema or route name
+    pub fn changes(&self) -> Vec<ReportedChange> {
+        let route_results = self.route_results.iter().chain(self.event_results);
+        let route_side_levels = RouteSideLevels::new(route_results.clone());
+
+        let mut changes: Vec<ReportedChange> = self
+            .schema_results
+            .iter()
+            .flat_map(|result| result.violations.iter().map(move |v| (result, v)))
+            .map(|(result, violation)| {
+                let mut change = violation.change();
+                change.level = route_side_levels.level_of(&result.name, &change);
+                (result, change)
+            })
+            .chain(route_results.flat_map(|result| {
+                result
+                    .violations
+                    .iter()
+                    .filter(|violation| {
+                        violation.name() != "RequestSchemaViolation"
+                            && violation.name() != "ResponseSchemaViolation"
+                    })
+                    .map(move |violation| (result, violation.change()))
+            }))
+            .map(|(result, change)| ReportedChange {
+                subject: result.name.clone(),
+                id: change.fingerprint(&result.name),
+                change,
+            })
+            .collect();
+
+        changes.sort_by(|a, b| a.subject.cmp(&b.subject));
+        changes
+    }
+}
+
+/// Readable title of a rule name, e.g. "Type changed" for "TypeChanged"
+pub fn rule_title(name: &str) -> String {
+    let mut title = String::new();
+    for (index, c) in name.chars().enumerate() {
+        if index > 0 && c.is_uppercase() {
+            title.push(' ');
+            title.extend(c.to_lowercase());
+        } else {
+            title.push(c);
+        }
+    }
+    title
+}
+
+/// Path of a file as shown in reports, e.g. `api/openapi.yaml` for `./api/openapi.yaml`
+pub fn display_path(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    path.strip_prefix("./").unwrap_or(&path).to_string()
+}
 
 /// Trait for rendering match results in different formats
 pub trait Renderer {
-    /// Render the match results and return the output as a string
-    fn render(&self, results: &[MatchResult]) -> Result<String, Box<dyn Error>>;
+    /// Render the report and return the output as a string
+    fn render(&self, report: &Report) -> Result<String, Box<dyn Error>>;
 
     /// Get the file extension for this renderer
     fn file_extension(&self) -> &str;
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::matcher::{RouteMatcher, SchemaMatcher};
+
+    /// Schema and route results of a response property made optional: a change to the
+    /// `User` schema alone, breaking for readers of `GET /users`
+    pub(crate) fn response_property_made_optional() -> (Vec<MatchResult>, Vec<MatchResult>) {
+        let spec = |required: &str| {
+            let content = format!(
+                r#"
+openapi: 3.0.3
+info: {{title: Test, version: "1"}}
+paths:
+  /users:
+    get:
+      responses:
+        "200":
+          description: User
+          content:
+            application/json:
+              schema: {{$ref: '#/components/schemas/User'}}
+components:
+  schemas:
+    User: {{type: object, required: {required}, properties: {{id: {{type: string}}, name: {{type: string}}}}}}
+"#
+            );
+            crate::loader::parse_spec(&content, Path::new("openapi.yaml"))
+                .unwrap()
+                .0
+        };
+        let base = spec("[id, name]");
+        let current = spec("[id]");
+
+        let base_schemas = &base.components.as_ref().unwrap().schemas;
+        let current_schemas = &current.components.as_ref().unwrap().schemas;
+        let schema_results =
+            SchemaMatcher::new(base_schemas, current_schemas, &base, &current).match_schemas();
+        let route_results =
+            RouteMatcher::new(&base, &current).match_routes_with_schema_violations(&schema_results);
+        (schema_results, route_results)
+    }
+
+    /// Report of the given results, without route or schema infos
+    pub(crate) fn report<'a>(
+        schema_results: &'a [MatchResult],
+        route_results: &'a [MatchResult],
+    ) -> Report<'a> {
+        Report {
+            schema_results,
+            route_results,
+            route_infos: &[],
+            event_results: &[],
+            event_infos: &[],
+            full_schema_infos: &[],
+            current_file: Some(Path::new("openapi.yaml")),
+            base_version: "1.0.0",
+            current_version: "1.1.0",
+        }
+    }
+
+    #[test]
+    fn test_schema_changes_get_their_route_side_level() {
+        let (schema_results, route_results) = response_property_made_optional();
+        assert_eq!(schema_results[0].change_level, crate::ChangeLevel::Change);
+
+        let changes = report(&schema_results, &route_results).changes();
+
+        // Reported once, for the schema, at its level for readers of the response
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].subject, "User");
+        assert_eq!(changes[0].change.rule, "RequiredPropertyRemoved");
+        assert_eq!(changes[0].change.level, crate::ChangeLevel::Breaking);
+        assert_eq!(
+            changes[0].id,
+            schema_results[0].violations[0].change().fingerprint("User")
+        );
+    }
+
+    #[test]
+    fn test_rule_title() {
+        assert_eq!(rule_title("TypeChanged"), "Type changed");
+        assert_eq!(rule_title("EnumChanged"), "Enum changed");
+    }
+
+    #[test]
+    fn test_display_path() {
+        assert_eq!(
+            display_path(Path::new("./api/openapi.yaml")),
+            "api/openapi.yaml"
+        );
+        assert_eq!(display_path(Path::new("openapi.json")), "openapi.json");
+    }
+}
@@ -0,0 +1,317 @@
+use crate::render::{Renderer, Report, ReportedChange};
+use crate::rules::{Change, ChangeLocation};
+use crate::ChangeLevel;
+use std::error::Error;
+use std::fmt::Write;
+
+/// Rules about documentation only, they don't affect API consumers
+const INTERNAL_RULES: &[&str] = &[
+    "DescriptionChanged",
+    "RouteDescriptionChanged",
+    "RouteSummaryChanged",
+    "ExampleChanged",
+    "DefsChanged",
+];
+
+/// Changelog section of a change, in the order sections are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Section {
+    Breaking,
+    Added,
+    Changed,
+    Deprecated,
+    Removed,
+}
+
+impl Section {
+    const ALL: [Section; 5] = [
+        Section::Breaking,
+        Section::Added,
+        Section::Changed,
+        Section::Deprecated,
+        Section::Removed,
+    ];
+
+    /// Section for a change, `None` for changes consumers don't need to know about
+    fn of(change: &Change) -> Option<Section> {
+        let rule = change.rule.as_str();
+        if INTERNAL_RULES.contains(&rule) {
+            return None;
+        }
+
+        Some(if change.level == ChangeLevel::Breaking {
+            Section::Breaking
+        } else if rule == "RouteDeprecated" {
+            Section::Deprecated
+        } else if rule == "RequiredPropertyRemoved" {
+            // Not breaking when the property was only made optional
+            Section::Changed
+        } else if rule.ends_with("Added") {
+            Section::Added
+        } else if rule.ends_with("Removed") {
+            Section::Removed
+        } else {
+            Section::Changed
+        })
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            Section::Breaking => "Breaking",
+            Section::Added => "Added",
+            Section::Changed => "Changed",
+            Section::Deprecated => "Deprecated",
+            Section::Removed => "Removed",
+        }
+    }
+}
+
+/// Renders a consumer-facing changelog in the Keep a Changelog style, with changes grouped
+/// into Breaking / Added / Changed / Deprecated / Removed sections
+pub struct ChangelogRenderer;
+
+impl ChangelogRenderer {
+    /// Changelog entry of a change, worded for API consumers
+    fn entry(reported: &ReportedChange) -> String {
+        let change = &reported.change;
+        let subject = &reported.subject;
+        // Schema changes are shown at the changed property, e.g. `User.address.city`
+        let target = match (&change.schema_name, &change.path) {
+            (Some(schema), Some(path)) if change.location.is_none() => {
+                format!("{}.{}", schema, path)
+            }
+            _ => subject.clone(),
+        };
+        let old_value = value_text(change.old_value.as_ref());
+        let new_value = value_text(change.new_value.as_ref());
+        let became = change.new_value == Some(serde_json::Value::Bool(true));
+
+        match (change.rule.as_str(), &change.location) {
+            ("RouteAdded", _) => format!("New endpoint `{}`.", subject),
+            ("RouteRemoved", _) => format!("Endpoint `{}` was removed.", subject),
+            ("RouteDeprecated", _) => format!("Endpoint `{}` is deprecated.", subject),
+            ("SchemaAdded", _) => format!("New schema `{}`.", subject),
+            ("SchemaRemoved", _) => format!("Schema `{}` was removed.", subject),
+            ("RequiredParameterAdded", Some(ChangeLocation::Parameter { name, location })) => {
+                format!(
+                    "`{}` requires the new {} parameter `{}`.",
+                    subject,
+                    location.to_lowercase(),
+                    name
+                )
+            }
+            ("ParameterRemoved", Some(ChangeLocation::Parameter { name, location })) => format!(
+                "`{}` no longer accepts the {} parameter `{}`.",
+                subject,
+                location.to_lowercase(),
+                name
+            ),
+            ("ResponseStatusAdded", Some(ChangeLocation::Response { status_code, .. })) => {
+                format!("`{}` can respond with status {}.", subject, status_code)
+            }
+            ("ResponseStatusRemoved", Some(ChangeLocation::Response { status_code, .. })) => {
+                format!(
+                    "`{}` no longer responds with status {}.",
+                    subject, status_code
+                )
+            }
+            ("PropertyAdded", None) => format!("New property `{}`.", target),
+            ("PropertyRemoved", None) => format!("Property `{}` was removed.", target),
+            ("RequiredPropertyAdded", None) => format!("`{}` is now required.", target),
+            ("RequiredPropertyRemoved", None) if change.level == ChangeLevel::Breaking => {
+                format!("Required property `{}` was removed.", target)
+            }
+            ("RequiredPropertyRemoved", None) => format!("`{}` is now optional.", target),
+            ("TypeChanged", None) => format!(
+                "Type of `{}` changed from {} to {}.",
+                target, old_value, new_value
+            ),
+            ("FormatChanged", None) => format!(
+                "Format of `{}` changed from {} to {}.",
+                target, old_value, new_value
+            ),
+            ("DefaultChanged", None) => format!(
+                "Default of `{}` changed from {} to {}.",
+                target, old_value, new_value
+            ),
+            ("EnumChanged", None) => format!("Allowed values of `{}` changed.", target),
+            ("NullableChanged", None) if became => format!("`{}` can be null.", target),
+            ("NullableChanged", None) => format!("`{}` can no longer be null.", target),
+            ("ReadOnlyChanged", None) if became => format!(
+                "`{}` is read-only, values sent in requests are ignored.",
+                target
+            ),
+            ("ReadOnlyChanged", None) => format!("`{}` is no longer read-only.", target),
+            ("WriteOnlyChanged", None) if became => format!(
+                "`{}` is write-only and no longer returned in responses.",
+                target
+            ),
+            ("WriteOnlyChanged", None) => format!("`{}` is no longer write-only.", target),
+            _ => format!("`{}`: {}.", target, change.description),
+        }
+    }
+}
+
+/// Value of a change for an entry, e.g. `integer`, or "none" when missing
+fn value_text(value: Option<&serde_json::Value>) -> String {
+    match value {
+        Some(serde_json::Value::String(text)) => format!("`{}`", text),
+        Some(value) => format!("`{}`", value),
+        None => "none".to_string(),
+    }
+}
+
+impl Renderer for ChangelogRenderer {
+    fn render(&self, report: &Report) -> Result<String, Box<dyn Error>> {
+        let mut entries: Vec<(Section, String)> = report
+            .changes()
+            .iter()
+            .filter_map(|reported| {
+                Section::of(&reported.change).map(|section| (section, Self::entry(reported)))
+            })
+            .collect();
+        entries.sort();
+        entries.dedup();
+
+        let mut output = String::from("# Changelog\n\n");
+        let _ = writeln!(
+            output,
+            "## [{}] - since {}\n",
+            report.current_version, report.base_version
+        );
+
+        if entries.is_empty() {
+            output.push_str("No changes for API consumers.\n");
+            return Ok(output);
+        }
+
+        for section in Section::ALL {
+            let mut lines = entries
+                .iter()
+                .filter(|(entry_section, _)| *entry_section == section)
+                .peekable();
+            if lines.peek().is_none() {
+                continue;
+            }
+
+            let _ = writeln!(output, "### {}\n", section.title());
+            for (_, line) in lines {
+                let _ = writeln!(output, "- {}", line);
+            }
+            output.push('\n');
+        }
+
+        Ok(output.trim_end().to_string() + "\n")
+    }
+
+    fn file_extension(&self) -> &str {
+        "md"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::route::{RouteAddedRule, RouteDeprecatedRule, RouteDescriptionChangedRule};
+    use crate::rules::schema::{PropertyAddedRule, RequiredPropertyAddedRule, TypeChangedRule};
+    use crate::rules::{MatchResult, RuleViolation};
+
+    #[test]
+    fn test_changelog() {
+        let schema_results = vec![MatchResult::new(
+            "User".to_string(),
+            vec![
+                RuleViolation::new(Box::new(TypeChangedRule {
+                    schema_name: "User".to_string(),
+                    property_path: "age".to_string(),
+                    old_type: "integer".to_string(),
+                    new_type: "string".to_string(),
+                })),
+                RuleViolation::new(Box::new(PropertyAddedRule {
+                    schema_name: "User".to_string(),
+                    property_path: String::new(),
+                    property_name: "email".to_string(),
+                })),
+                RuleViolation::new(Box::new(RequiredPropertyAddedRule {
+                    schema_name: "User".to_string(),
+                    property_path: String::new(),
+                    property_name: "email".to_string(),
+                })),
+            ],
+        )];
+        let route_results = vec![
+            MatchResult::new(
+                "GET /orders".to_string(),
+                vec![RuleViolation::new(Box::new(RouteAddedRule {
+                    path: "/orders".to_string(),
+                    method: "get".to_string(),
+                }))],
+            ),
+            MatchResult::new(
+                "GET /users".to_string(),
+                vec![
+                    RuleViolation::new(Box::new(RouteDeprecatedRule {
+                        path: "/users".to_string(),
+                        method: "get".to_string(),
+                    })),
+                    RuleViolation::new(Box::new(RouteDescriptionChangedRule {
+                        path: "/users".to_string(),
+                        method: "get".to_string(),
+                        old_description: "Users".to_string(),
+                        new_description: "All users".to_string(),
+                    })),
+                ],
+            ),
+        ];
+        let report = Report {
+            schema_results: &schema_results,
+            route_results: &route_results,
+            route_infos: &[],
+            event_results: &[],
+            event_infos: &[],
+            full_schema_infos: &[],
+            current_file: None,
+            base_version: "1.0.0",
+            current_version: "1.1.0",
+        };
+
+        let expected = "\
+# Changelog
+
+## [1.1.0] - since 1.0.0
+
+### Breaking
+
+- Type of `User.age` changed from `integer` to `string`.
+- `User.email` is now required.
+
+### Added
+
+- New endpoint `GET /orders`.
+- New property `User.email`.
+
+### Deprecated
+
+- Endpoint `GET /users` is deprecated.
+";
+        assert_eq!(ChangelogRenderer.render(&report).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_route_side_breaking_change_is_listed_as_breaking() {
+        let (schema_results, route_results) =
+            crate::render::tests::response_property_made_optional();
+        let report = crate::render::tests::report(&schema_results, &route_results);
+
+        let expected = "\
+# Changelog
+
+## [1.1.0] - since 1.0.0
+
+### Breaking
+
+- Required property `User.name` was removed.
+";
+        assert_eq!(ChangelogRenderer.render(&report).unwrap(), expected);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::render::{display_path, rule_title, Renderer, Report, ReportedChange};
+use crate::ChangeLevel;
+use std::error::Error;
+
+/// Renders GitHub Actions workflow commands (`::error file=...,line=...::message`),
+/// so changes are annotated inline on the spec file when printed in a workflow step
+pub struct GithubRenderer;
+
+impl GithubRenderer {
+    fn command(reported: &ReportedChange, file: Option<&str>) -> String {
+        let change = &reported.change;
+        let command = match change.level {
+            ChangeLevel::Breaking => "error",
+            ChangeLevel::Warning => "warning",
+            ChangeLevel::Change => "notice",
+        };
+
+        let mut properties = Vec::new();
+        if let Some(file) = file {
+            properties.push(format!("file={}", escape_property(file)));
+            if let Some(source) = change.current_node.as_ref().and_then(|node| node.source) {
+                properties.push(format!("line={}", source.line));
+                properties.push(format!("col={}", source.column));
+            }
+        }
+        properties.push(format!(
+            "title={}",
+            escape_property(&rule_title(&change.rule))
+        ));
+
+        let message = format!(
+            "{}: {} (#{})",
+            reported.subject, change.description, reported.id
+        );
+        format!(
+            "::{} {}::{}",
+            command,
+            properties.join(","),
+            escape_data(&message)
+        )
+    }
+}
+
+impl Renderer for GithubRenderer {
+    fn render(&self, report: &Report) -> Result<String, Box<dyn Error>> {
+        let file = report.current_file.map(display_path);
+
+        let mut output = String::new();
+        for reported in report.changes() {
+            output.push_str(&Self::command(&reported, file.as_deref()));
+            output.push('\n');
+        }
+        Ok(output)
+    }
+
+    fn file_extension(&self) -> &str {
+        "txt"
+    }
+}
+
+/// Escape the message of a workflow command
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property value of a workflow command
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::route::RouteRemovedRule;
+    use crate::rules::schema::SchemaAddedRule;
+    use crate::rules::{MatchResult, RuleViolation};
+    use std::path::Path;
+
+    #[test]
+    fn test_workflow_commands() {
+        let route_results = vec![MatchResult::new(
+            "DELETE /users/{id}".to_string(),
+            vec![RuleViolation::new(Box::new(RouteRemovedRule {
+                path: "/users/{id}".to_string(),
+                method: "delete".to_string(),
+            }))],
+        )];
+        let schema_results = vec![MatchResult::new(
+            "Discount%".to_string(),
+            vec![RuleViolation::new(Box::new(SchemaAddedRule {
+                schema_name: "Discount%".to_string(),
+            }))],
+        )];
+        let report = Report {
+            schema_results: &schema_results,
+            route_results: &route_results,
+            route_infos: &[],
+            event_results: &[],
+            event_infos: &[],
+            full_schema_infos: &[],
+            current_file: Some(Path::new("./api/openapi,v2.yaml")),
+            base_version: "1.0.0",
+            current_version: "1.0.0",
+        };
+
+        let output = GithubRenderer.render(&report).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with(
+            "::error file=api/openapi%2Cv2.yaml,title=Route removed::DELETE /users/{id}: "
+        ));
+        assert!(lines[1].starts_with(
+            "::notice file=api/openapi%2Cv2.yaml,title=Schema added::Discount%25: Schema 'Discount%25' was added (#"
+        ));
+    }
+
+    #[test]
+    fn test_route_side_breaking_change_is_an_error() {
+        let (schema_results, route_results) =
+            crate::render::tests::response_property_made_optional();
+        let report = crate::render::tests::report(&schema_results, &route_results);
+
+        let output = GithubRenderer.render(&report).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].starts_with(
+            "::error file=openapi.yaml,title=Required property removed::User: Required property 'name' was removed"
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+use crate::render::{display_path, Renderer, Report, ReportedChange};
+use crate::ChangeLevel;
+use serde::Serialize;
+use std::error::Error;
+
+/// File name used for specs read from stdin, GitLab requires a path for every issue
+const STDIN_PATH: &str = "openapi";
+
+/// Renders a GitLab Code Quality report (Code Climate JSON), so changes are shown in the
+/// merge request widget and annotated inline on the spec file
+pub struct GitlabRenderer;
+
+/// Code Climate issue, with the fields GitLab reads
+#[derive(Serialize)]
+struct Issue<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    check_name: &'a str,
+    description: String,
+    categories: [&'static str; 1],
+    severity: &'static str,
+    fingerprint: &'a str,
+    location: IssueLocation,
+}
+
+#[derive(Serialize)]
+struct IssueLocation {
+    path: String,
+    lines: IssueLines,
+}
+
+#[derive(Serialize)]
+struct IssueLines {
+    begin: usize,
+}
+
+impl GitlabRenderer {
+    fn issue<'a>(reported: &'a ReportedChange, path: &str) -> Issue<'a> {
+        let change = &reported.change;
+        let line = change
+            .current_node
+            .as_ref()
+            .and_then(|node| node.source)
+            .map(|source| source.line);
+
+        Issue {
+            kind: "issue",
+            check_name: &change.rule,
+            description: format!("{}: {}", reported.subject, change.description),
+            categories: ["Compatibility"],
+            severity: match change.level {
+                ChangeLevel::Breaking => "critical",
+                ChangeLevel::Warning => "major",
+                ChangeLevel::Change => "info",
+            },
+            fingerprint: &reported.id,
+            location: IssueLocation {
+                path: path.to_string(),
+                // Changes without a known line are reported at the top of the file
+                lines: IssueLines {
+                    begin: line.unwrap_or(1),
+                },
+            },
+        }
+    }
+}
+
+impl Renderer for GitlabRenderer {
+    fn render(&self, report: &Report) -> Result<String, Box<dyn Error>> {
+        let path = report
+            .current_file
+            .map(display_path)
+            .unwrap_or_else(|| STDIN_PATH.to_string());
+
+        let changes = report.changes();
+        let issues: Vec<Issue> = changes
+            .iter()
+            .map(|reported| Self::issue(reported, &path))
+            .collect();
+
+        Ok(serde_json::to_string_pretty(&issues)?)
+    }
+
+    fn file_extension(&self) -> &str {
+        "json"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::schema::SchemaRemovedRule;
+    use crate::rules::{MatchResult, RuleViolation};
+
+    #[test]
+    fn test_code_quality_report() {
+        let schema_results = vec![MatchResult::new(
+            "User".to_string(),
+            vec![RuleViolation::new(Box::new(SchemaRemovedRule {
+                schema_name: "User".to_string(),
+            }))],
+        )];
+        let report = Report {
+            schema_results: &schema_results,
+            route_results: &[],
+            route_infos: &[],
+            event_results: &[],
+            event_infos: &[],
+            full_schema_infos: &[],
+            current_file: None,
+            base_version: "1.0.0",
+            current_version: "1.0.0",
+        };
+
+        let output = GitlabRenderer.render(&report).unwrap();
+        let issues: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let issue = &issues[0];
+        assert_eq!(issue["check_name"], "SchemaRemoved");
+        assert_eq!(issue["description"], "User: Schema 'User' was removed");
+        assert_eq!(issue["severity"], "critical");
+        assert_eq!(issue["fingerprint"].as_str().unwrap().len(), 12);
+        assert_eq!(issue["location"]["path"], "openapi");
+        assert_eq!(issue["location"]["lines"]["begin"], 1);
+    }
+
+    #[test]
+    fn test_route_side_breaking_change_is_critical() {
+        let (schema_results, route_results) =
+            crate::render::tests::response_property_made_optional();
+        let report = crate::render::tests::report(&schema_results, &route_results);
+
+        let output = GitlabRenderer.render(&report).unwrap();
+        let issues: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(issues.as_array().unwrap().len(), 1);
+        assert_eq!(issues[0]["check_name"], "RequiredPropertyRemoved");
+        assert_eq!(issues[0]["severity"], "critical");
+    }
+}
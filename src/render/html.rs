@@ -1,16 +1,19 @@
-use crate::matcher::{RouteInfo, SchemaLocation, SchemaReference};
-use crate::render::Renderer;
-use crate::rules::{MatchResult, RuleViolation};
+use crate::matcher::{RouteInfo, RouteKind, SchemaLocation, SchemaReference};
+use crate::render::{Renderer, Report};
+use crate::rules::{Change, ChangeLocation, MatchResult, NodeLocation, RuleViolation};
 use crate::ChangeLevel;
 use serde::Serialize;
 use std::collections::HashMap;
 use std::error::Error;
+use std::fs;
+use std::path::Path;
 use tera::{Context, Tera};
 
 #[derive(Serialize)]
 struct TemplateData {
     schemas: Vec<SchemaData>,
     routes: Vec<RouteData>,
+    event_routes: Vec<RouteData>,
     stats: Stats,
     grouped_changes: Vec<GroupedChange>,
     full_schemas: Vec<crate::rules::FullSchemaInfo>,
@@ -37,6 +40,7 @@ struct RouteData {
     name: String,
     path: String,
     method: String,
+    kind_label: Option<String>, // set for webhooks and callbacks
     change_level: String,
     change_level_class: String,
     differences: Vec<DifferenceData>,
@@ -57,6 +61,8 @@ struct SchemaLinkData {
 
 #[derive(Serialize, Clone)]
 struct DifferenceData {
+    id: String,                 // stable fingerprint, used as permalink anchor
+    node: Option<NodeLocation>, // source node, shown as its line
     emoji: String,
     description: String,
     change_level: String,
@@ -88,70 +94,101 @@ struct GroupedChange {
     schema_name: Option<String>, // The main schema name (for schema-grouped items)
     route_names: Vec<String>,    // Routes that use this schema
     route_schema_usage: Vec<RouteSchemaUsage>, // Detailed usage info for each route
+    ids: Vec<String>,            // fingerprints of all grouped occurrences, for permalinks
 }
 
 #[derive(Serialize, Clone)]
 struct RouteSchemaUsage {
     route_name: String,
-    usage_type: String, // "request" or "response"
+    usage_type: String, // "input" or "output"
     emoji: String,      // "📤" for request, "📥" for response
 }
 
 #[derive(Serialize, Clone)]
 struct ChangeItem {
+    id: String,
+    node: Option<NodeLocation>,
     emoji: String,
     description: String,
     change_level: String,
     change_level_class: String,
 }
 
+/// Version of the template context, see `docs/template-context.md`.
+/// Bumped when fields are renamed or removed, adding fields keeps the version
+pub const TEMPLATE_CONTEXT_VERSION: u32 = 1;
+
+/// Template rendered as the report, other templates are included from it
+const ENTRY_TEMPLATE: &str = "report.html";
+
+/// Templates built into the binary
+const EMBEDDED_TEMPLATES: &[(&str, &str)] = &[
+    ("report.html", include_str!("../../templates/report.html")),
+    (
+        "components/base_styles.html",
+        include_str!("../../templates/components/base_styles.html"),
+    ),
+    (
+        "components/header.html",
+        include_str!("../../templates/components/header.html"),
+    ),
+    (
+        "components/stats.html",
+        include_str!("../../templates/components/stats.html"),
+    ),
+    (
+        "components/help.html",
+        include_str!("../../templates/components/help.html"),
+    ),
+    (
+        "components/grouped_changes.html",
+        include_str!("../../templates/components/grouped_changes.html"),
+    ),
+    (
+        "components/route_card.html",
+        include_str!("../../templates/components/route_card.html"),
+    ),
+    (
+        "components/routes.html",
+        include_str!("../../templates/components/routes.html"),
+    ),
+    (
+        "components/schemas.html",
+        include_str!("../../templates/components/schemas.html"),
+    ),
+    (
+        "components/scripts.html",
+        include_str!("../../templates/components/scripts.html"),
+    ),
+];
+
 pub struct HtmlRenderer {
     tera: Tera,
 }
 
 impl HtmlRenderer {
     pub fn new() -> Result<Self, Box<dyn Error>> {
-        // Load templates from the templates directory
         let mut tera = Tera::default();
+        tera.add_raw_templates(EMBEDDED_TEMPLATES.iter().copied())
+            .map_err(|err| describe_tera_error(&err))?;
 
-        // Load main template
-        let _ = tera.add_raw_template("report.html", include_str!("../../templates/report.html"));
+        Ok(Self { tera })
+    }
 
-        // Load component templates
-        let _ = tera.add_raw_template(
-            "components/base_styles.html",
-            include_str!("../../templates/components/base_styles.html"),
-        );
-        let _ = tera.add_raw_template(
-            "components/header.html",
-            include_str!("../../templates/components/header.html"),
-        );
-        let _ = tera.add_raw_template(
-            "components/stats.html",
-            include_str!("../../templates/components/stats.html"),
-        );
-        let _ = tera.add_raw_template(
-            "components/help.html",
-            include_str!("../../templates/components/help.html"),
-        );
-        let _ = tera.add_raw_template(
-            "components/grouped_changes.html",
-            include_str!("../../templates/components/grouped_changes.html"),
-        );
-        let _ = tera.add_raw_template(
-            "components/routes.html",
-            include_str!("../../templates/components/routes.html"),
-        );
-        let _ = tera.add_raw_template(
-            "components/schemas.html",
-            include_str!("../../templates/components/schemas.html"),
-        );
-        let _ = tera.add_raw_template(
-            "components/scripts.html",
-            include_str!("../../templates/components/scripts.html"),
-        );
+    /// Use templates from `dir` in place of the embedded ones with the same name,
+    /// e.g. `dir/components/header.html` replaces the header. Templates missing from `dir`
+    /// fall back to the embedded ones
+    pub fn with_template_dir(mut self, dir: &Path) -> Result<Self, Box<dyn Error>> {
+        let mut templates = Vec::new();
+        collect_templates(dir, dir, &mut templates)?;
+        if templates.is_empty() {
+            return Err(format!("No templates found in {}", dir.display()).into());
+        }
 
-        Ok(Self { tera })
+        self.tera
+            .add_raw_templates(templates)
+            .map_err(|err| describe_tera_error(&err))?;
+        Ok(self)
     }
 
     /// Render HTML report with routes and schemas
@@ -160,18 +197,26 @@ impl HtmlRenderer {
         schema_results: &[MatchResult],
         route_results: &[MatchResult],
         route_infos: &[RouteInfo],
+        event_results: &[MatchResult],
+        event_infos: &[RouteInfo],
         full_schema_infos: &[crate::rules::FullSchemaInfo],
     ) -> Result<String, Box<dyn Error>> {
         let data = self.convert_to_template_data_with_routes(
             schema_results,
             route_results,
             route_infos,
+            event_results,
+            event_infos,
             full_schema_infos,
         );
         let mut context = Context::new();
+        context.insert("context_version", &TEMPLATE_CONTEXT_VERSION);
         context.insert("data", &data);
 
-        let html = self.tera.render("report.html", &context)?;
+        let html = self
+            .tera
+            .render(ENTRY_TEMPLATE, &context)
+            .map_err(|err| describe_tera_error(&err))?;
         Ok(html)
     }
 
@@ -180,6 +225,8 @@ impl HtmlRenderer {
         schema_results: &[MatchResult],
         route_results: &[MatchResult],
         route_infos: &[RouteInfo],
+        event_results: &[MatchResult],
+        event_infos: &[RouteInfo],
         full_schema_infos: &[crate::rules::FullSchemaInfo],
     ) -> TemplateData {
         let mut breaking_count = 0;
@@ -187,7 +234,11 @@ impl HtmlRenderer {
         let mut change_count = 0;
 
         // Count individual violations instead of MatchResults
-        for result in schema_results.iter().chain(route_results.iter()) {
+        for result in schema_results
+            .iter()
+            .chain(route_results.iter())
+            .chain(event_results.iter())
+        {
             for violation in &result.violations {
                 match violation.change_level() {
                     ChangeLevel::Breaking => breaking_count += 1,
@@ -198,11 +249,16 @@ impl HtmlRenderer {
         }
 
         // Group schema and route changes separately and combine
+        let all_route_infos: Vec<RouteInfo> =
+            route_infos.iter().chain(event_infos).cloned().collect();
         let mut grouped_changes =
-            self.group_repeating_changes_with_route_infos(schema_results, route_infos);
+            self.group_repeating_changes_with_route_infos(schema_results, &all_route_infos);
         let route_grouped =
             self.group_repeating_changes_with_route_infos(route_results, route_infos);
         grouped_changes.extend(route_grouped);
+        let event_grouped =
+            self.group_repeating_changes_with_route_infos(event_results, event_infos);
+        grouped_changes.extend(event_grouped);
 
         // Convert schema results
         let schemas: Vec<SchemaData> = schema_results
@@ -217,7 +273,7 @@ impl HtmlRenderer {
                 let differences = result
                     .violations
                     .iter()
-                    .map(|violation| self.convert_violation(violation))
+                    .map(|violation| self.convert_violation(violation, &result.name))
                     .collect();
 
                 SchemaData {
@@ -230,89 +286,21 @@ impl HtmlRenderer {
             .collect();
 
         // Convert route results
-        let routes: Vec<RouteData> = route_results
-            .iter()
-            .map(|result| {
-                let (change_level, change_level_class) = match result.change_level {
-                    ChangeLevel::Breaking => {
-                        breaking_count += 1;
-                        ("Breaking".to_string(), "breaking".to_string())
-                    }
-                    ChangeLevel::Warning => {
-                        warning_count += 1;
-                        ("Warning".to_string(), "warning".to_string())
-                    }
-                    ChangeLevel::Change => {
-                        change_count += 1;
-                        ("Change".to_string(), "change".to_string())
-                    }
-                };
-
-                // Filter out schema violations and detect schema changes
-                let mut differences = Vec::new();
-                let mut has_request_schema_changes = false;
-                let mut has_response_schema_changes = false;
-
-                for violation in &result.violations {
-                    let diff = self.convert_violation(violation);
-                    let description = &diff.description;
-
-                    if description.contains("Request schema")
-                        || description.contains("RequestSchemaViolation")
-                    {
-                        has_request_schema_changes = true;
-                    } else if description.contains("Response schema")
-                        || description.contains("ResponseSchemaViolation")
-                    {
-                        has_response_schema_changes = true;
-                    } else {
-                        // Only include non-schema violations in differences
-                        differences.push(diff);
-                    }
-                }
-
-                // Find route info for this route
-                let parts: Vec<&str> = result.name.split_whitespace().collect();
-                let method = parts.get(0).unwrap_or(&"").to_lowercase();
-                let path = parts.get(1).unwrap_or(&"");
-
-                let route_info = route_infos
-                    .iter()
-                    .find(|r| r.method == method && r.path == *path);
-
-                // Get list of schemas with changes for filtering
-                let schemas_with_changes: std::collections::HashSet<String> =
-                    schema_results.iter().map(|r| r.name.clone()).collect();
-
-                let (request_schemas, response_schemas) = if let Some(info) = route_info {
-                    (
-                        self.convert_schema_references(
-                            &info.request_schemas,
-                            &schemas_with_changes,
-                        ),
-                        self.convert_schema_references(
-                            &info.response_schemas,
-                            &schemas_with_changes,
-                        ),
-                    )
-                } else {
-                    (vec![], vec![])
-                };
-
-                RouteData {
-                    name: result.name.clone(),
-                    path: path.to_string(),
-                    method: method.to_uppercase(),
-                    change_level,
-                    change_level_class,
-                    differences,
-                    request_schemas,
-                    response_schemas,
-                    has_request_schema_changes,
-                    has_response_schema_changes,
-                }
-            })
-            .collect();
+        let mut routes = Vec::new();
+        let mut event_routes = Vec::new();
+        for result in route_results.iter().chain(event_results.iter()) {
+            match result.change_level {
+                ChangeLevel::Breaking => breaking_count += 1,
+                ChangeLevel::Warning => warning_count += 1,
+                ChangeLevel::Change => change_count += 1,
+            }
+        }
+        for result in route_results {
+            routes.push(self.convert_route_result(result, schema_results));
+        }
+        for result in event_results {
+            event_routes.push(self.convert_route_result(result, schema_results));
+        }
 
         TemplateData {
             stats: Stats {
@@ -323,11 +311,84 @@ impl HtmlRenderer {
             },
             schemas,
             routes,
+            event_routes,
             grouped_changes,
             full_schemas: full_schema_infos.to_vec(),
         }
     }
 
+    /// Convert a route (or webhook/callback) result, taking method and path from its route info
+    fn convert_route_result(
+        &self,
+        result: &MatchResult,
+        schema_results: &[MatchResult],
+    ) -> RouteData {
+        let (change_level, change_level_class) = match result.change_level {
+            ChangeLevel::Breaking => ("Breaking".to_string(), "breaking".to_string()),
+            ChangeLevel::Warning => ("Warning".to_string(), "warning".to_string()),
+            ChangeLevel::Change => ("Change".to_string(), "change".to_string()),
+        };
+
+        // Filter out schema violations and detect schema changes
+        let mut differences = Vec::new();
+        let mut has_request_schema_changes = false;
+        let mut has_response_schema_changes = false;
+
+        for violation in &result.violations {
+            let change = violation.change();
+            match (&change.schema_name, &change.location) {
+                (Some(_), Some(ChangeLocation::RequestBody { .. })) => {
+                    has_request_schema_changes = true;
+                }
+                (Some(_), Some(ChangeLocation::Response { .. })) => {
+                    has_response_schema_changes = true;
+                }
+                // Only include non-schema violations in differences
+                _ => differences.push(self.convert_violation(violation, &result.name)),
+            }
+        }
+
+        let route_info = result.route.as_ref();
+        let (method, path) = route_info
+            .map(|info| (info.method.clone(), info.path.clone()))
+            .unwrap_or_default();
+
+        let kind_label = match route_info.map(|info| &info.kind) {
+            Some(RouteKind::Webhook) => Some("Webhook".to_string()),
+            Some(RouteKind::Callback { operation, name }) => {
+                Some(format!("Callback '{}' of {}", name, operation))
+            }
+            _ => None,
+        };
+
+        // Get list of schemas with changes for filtering
+        let schemas_with_changes: std::collections::HashSet<String> =
+            schema_results.iter().map(|r| r.name.clone()).collect();
+
+        let (request_schemas, response_schemas) = if let Some(info) = route_info {
+            (
+                self.convert_schema_references(&info.request_schemas, &schemas_with_changes),
+                self.convert_schema_references(&info.response_schemas, &schemas_with_changes),
+            )
+        } else {
+            (vec![], vec![])
+        };
+
+        RouteData {
+            name: result.name.clone(),
+            path,
+            method: method.to_uppercase(),
+            kind_label,
+            change_level,
+            change_level_class,
+            differences,
+            request_schemas,
+            response_schemas,
+            has_request_schema_changes,
+            has_response_schema_changes,
+        }
+    }
+
     fn convert_schema_references(
         &self,
         refs: &[SchemaReference],
@@ -350,88 +411,29 @@ impl HtmlRenderer {
             .collect()
     }
 
-    fn convert_to_template_data(&self, results: &[MatchResult]) -> TemplateData {
-        let mut breaking_count = 0;
-        let mut warning_count = 0;
-        let mut change_count = 0;
-
-        // Count individual violations instead of MatchResults
-        for result in results {
-            for violation in &result.violations {
-                match violation.change_level() {
-                    ChangeLevel::Breaking => breaking_count += 1,
-                    ChangeLevel::Warning => warning_count += 1,
-                    ChangeLevel::Change => change_count += 1,
-                }
-            }
-        }
-
-        let grouped_changes = self.group_repeating_changes(results);
-
-        let schemas: Vec<SchemaData> = results
-            .iter()
-            .map(|result| {
-                let (change_level, change_level_class) = match result.change_level {
-                    ChangeLevel::Breaking => ("Breaking".to_string(), "breaking".to_string()),
-                    ChangeLevel::Warning => ("Warning".to_string(), "warning".to_string()),
-                    ChangeLevel::Change => ("Change".to_string(), "change".to_string()),
-                };
-
-                let differences = result
-                    .violations
-                    .iter()
-                    .map(|violation| self.convert_violation(violation))
-                    .collect();
-
-                SchemaData {
-                    name: result.name.clone(),
-                    change_level,
-                    change_level_class,
-                    differences,
-                }
-            })
-            .collect();
-
-        TemplateData {
-            stats: Stats {
-                total_changes: breaking_count + warning_count + change_count,
-                breaking_changes: breaking_count,
-                warnings: warning_count,
-                non_breaking_changes: change_count,
-            },
-            schemas,
-            routes: vec![], // No routes in old method
-            grouped_changes,
-            full_schemas: vec![], // No full schemas in old method
-        }
-    }
-
-    fn group_repeating_changes(&self, results: &[MatchResult]) -> Vec<GroupedChange> {
-        self.group_repeating_changes_with_route_infos(results, &[])
-    }
-
     fn group_repeating_changes_with_route_infos(
         &self,
         results: &[MatchResult],
         route_infos: &[RouteInfo],
     ) -> Vec<GroupedChange> {
-        let mut change_map: HashMap<String, (DifferenceData, Vec<String>, bool)> = HashMap::new();
+        let mut change_map: HashMap<String, (DifferenceData, Vec<String>, bool, Vec<String>)> =
+            HashMap::new();
         let mut route_schema_map: HashMap<String, Vec<String>> = HashMap::new(); // schema_name -> routes using it
         let mut route_schema_usage_map: HashMap<String, Vec<RouteSchemaUsage>> = HashMap::new(); // schema_name -> detailed usage info
 
         // Build comprehensive schema-to-routes mapping from route_infos
         for route_info in route_infos {
-            let route_name = format!("{} {}", route_info.method.to_uppercase(), route_info.path);
+            let route_name = route_info.name();
 
             // Add request schemas
             for schema_ref in &route_info.request_schemas {
                 route_schema_map
                     .entry(schema_ref.schema_name.clone())
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push(route_name.clone());
                 route_schema_usage_map
                     .entry(schema_ref.schema_name.clone())
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push(RouteSchemaUsage {
                         route_name: route_name.clone(),
                         usage_type: "input".to_string(),
@@ -443,11 +445,11 @@ impl HtmlRenderer {
             for schema_ref in &route_info.response_schemas {
                 route_schema_map
                     .entry(schema_ref.schema_name.clone())
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push(route_name.clone());
                 route_schema_usage_map
                     .entry(schema_ref.schema_name.clone())
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push(RouteSchemaUsage {
                         route_name: route_name.clone(),
                         usage_type: "output".to_string(),
@@ -458,33 +460,20 @@ impl HtmlRenderer {
 
         // Collect all changes and their schemas
         for result in results {
-            // Check if this is a route (has HTTP method prefix)
-            let is_route = result.name.starts_with("GET ")
-                || result.name.starts_with("POST ")
-                || result.name.starts_with("PUT ")
-                || result.name.starts_with("DELETE ")
-                || result.name.starts_with("PATCH ")
-                || result.name.starts_with("HEAD ")
-                || result.name.starts_with("OPTIONS ");
+            let is_route = result.route.is_some();
 
             for violation in &result.violations {
-                let diff_data = self.convert_violation(violation);
+                let change = violation.change();
 
                 // Check if this is a route schema violation (RequestSchemaViolation or ResponseSchemaViolation)
                 let is_route_schema_violation = violation.name() == "RequestSchemaViolation"
                     || violation.name() == "ResponseSchemaViolation";
 
                 if is_route_schema_violation {
-                    // Extract schema name from route schema violation description
-                    // Format: "Request schema 'SchemaName' (content-type) - original_description"
-                    // or "Response schema 'SchemaName' (content-type) for status 200 - original_description"
-                    let description = violation.description();
-                    if let Some(schema_name) =
-                        self.extract_schema_name_from_route_violation(&description)
-                    {
+                    if let Some(schema_name) = change.schema_name {
                         route_schema_map
                             .entry(schema_name)
-                            .or_insert_with(Vec::new)
+                            .or_default()
                             .push(result.name.clone());
 
                         // Skip adding this to change_map as it will be handled by the schema change
@@ -492,18 +481,14 @@ impl HtmlRenderer {
                     }
                 }
 
-                let key = self.create_change_key(&diff_data);
+                let key = self.create_change_key(&change);
 
+                let diff_data = self.convert_violation(violation, &result.name);
                 let entry = change_map
                     .entry(key)
-                    .or_insert_with(|| (diff_data.clone(), Vec::new(), is_route));
+                    .or_insert_with(|| (diff_data.clone(), Vec::new(), is_route, Vec::new()));
                 entry.1.push(result.name.clone());
-
-                // If we already have this change, merge the descriptions
-                if entry.0.description != diff_data.description {
-                    entry.0.description =
-                        self.merge_descriptions(&entry.0.description, &diff_data.description);
-                }
+                entry.3.push(diff_data.id);
             }
         }
 
@@ -511,7 +496,7 @@ impl HtmlRenderer {
         let mut multi_occurrence: Vec<GroupedChange> = Vec::new();
         let mut single_occurrence: HashMap<String, Vec<(DifferenceData, bool)>> = HashMap::new();
 
-        for (key, (diff, mut schema_names, is_route)) in change_map {
+        for (key, (diff, mut schema_names, is_route, ids)) in change_map {
             schema_names.sort();
             schema_names.dedup();
 
@@ -547,13 +532,14 @@ impl HtmlRenderer {
                     schema_name: None,
                     route_names: all_route_names,
                     route_schema_usage: all_route_usage,
+                    ids,
                 });
             } else if schema_names.len() == 1 {
                 // Single schema - collect all changes for this schema
                 let schema_name = schema_names[0].clone();
                 single_occurrence
                     .entry(schema_name)
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push((diff, is_route));
             }
         }
@@ -585,6 +571,8 @@ impl HtmlRenderer {
             let change_items: Vec<ChangeItem> = changes
                 .iter()
                 .map(|(diff, _)| ChangeItem {
+                    id: diff.id.clone(),
+                    node: diff.node.clone(),
                     emoji: diff.emoji.clone(),
                     description: diff.description.clone(),
                     change_level: diff.change_level.clone(),
@@ -616,6 +604,7 @@ impl HtmlRenderer {
                 schema_name: Some(schema_name.clone()),
                 route_names,
                 route_schema_usage,
+                ids: vec![],
             });
         }
 
@@ -645,115 +634,22 @@ impl HtmlRenderer {
         multi_occurrence
     }
 
-    fn extract_schema_name_from_route_violation(&self, description: &str) -> Option<String> {
-        // Extract schema name from route schema violation description
-        // Format: "Request schema 'SchemaName' (content-type) - original_description"
-        // or "Response schema 'SchemaName' (content-type) for status 200 - original_description"
-
-        if description.starts_with("Request schema '") {
-            if let Some(start) = description.find("'") {
-                if let Some(end) = description[start + 1..].find("'") {
-                    return Some(description[start + 1..start + 1 + end].to_string());
-                }
-            }
-        } else if description.starts_with("Response schema '") {
-            if let Some(start) = description.find("'") {
-                if let Some(end) = description[start + 1..].find("'") {
-                    return Some(description[start + 1..start + 1 + end].to_string());
-                }
-            }
-        }
-
-        None
-    }
-
-    fn merge_descriptions(&self, desc1: &str, desc2: &str) -> String {
-        // If both are about the same property, create a combined description
-        if desc1.contains("Property Added") && desc2.contains("Required Properties Added") {
-            if let Some(prop1) = desc1.strip_prefix("Property Added: ") {
-                if desc2.contains(prop1) {
-                    return format!("Property Added (Required): {}", prop1);
-                }
-            }
-        } else if desc1.contains("Property Removed")
-            && desc2.contains("Required Properties Removed")
-        {
-            if let Some(prop1) = desc1.strip_prefix("Property Removed: ") {
-                if desc2.contains(prop1) {
-                    return format!("Property Removed (Required): {}", prop1);
-                }
-            }
-        }
-
-        // If descriptions are the same, return one of them
-        if desc1 == desc2 {
-            return desc1.to_string();
-        }
-
-        // Otherwise, return the more specific one (shorter description usually means more specific)
-        if desc1.len() < desc2.len() {
-            desc1.to_string()
-        } else {
-            desc2.to_string()
-        }
-    }
-
-    fn create_change_key(&self, diff: &DifferenceData) -> String {
-        // Extract property names from the description and details
-        let mut property_names = Vec::new();
-
-        // Extract from description (e.g., "Property Added: places" -> "places")
-        if diff.description.starts_with("Property Added: ") {
-            if let Some(prop) = diff.description.strip_prefix("Property Added: ") {
-                property_names.push(prop.to_string());
-            }
-        } else if diff.description.starts_with("Property Removed: ") {
-            if let Some(prop) = diff.description.strip_prefix("Property Removed: ") {
-                property_names.push(prop.to_string());
-            }
-        } else if diff.description.starts_with("Property Modified: ") {
-            if let Some(prop) = diff.description.strip_prefix("Property Modified: ") {
-                property_names.push(prop.to_string());
-            }
-        }
-
-        // Extract from details (for required properties)
-        for detail in &diff.details {
-            if detail.property_type == "Required" {
-                property_names.push(detail.content.clone());
-            }
-        }
-
-        // Sort property names to ensure consistent grouping
-        property_names.sort();
-        let properties_key = property_names.join(",");
-
-        // Create a base change type from the description
-        let change_type = if diff.description.contains("Property Added")
-            || diff.description.contains("Required Properties Added")
-        {
-            "property_added".to_string()
-        } else if diff.description.contains("Property Removed")
-            || diff.description.contains("Required Properties Removed")
-        {
-            "property_removed".to_string()
-        } else if diff.description.contains("Property Modified") {
-            "property_modified".to_string()
-        } else {
-            // For other changes, use the full description
-            diff.description.replace(" ", "_").to_lowercase()
-        };
-
+    /// Key of a change, equal for the same change found in different schemas
+    fn create_change_key(&self, change: &Change) -> String {
+        let values = serde_json::json!([change.path, change.old_value, change.new_value]);
         format!(
-            "{}:{}:{}",
-            change_type, diff.change_level_class, properties_key
+            "{}:{:?}:{}:{}",
+            change.rule, change.level, values, change.description
         )
     }
 
-    fn convert_violation(&self, violation: &RuleViolation) -> DifferenceData {
+    /// Convert a violation reported for `subject` (schema or route name)
+    fn convert_violation(&self, violation: &RuleViolation, subject: &str) -> DifferenceData {
         let rule = violation.rule();
         let rule_name = rule.name();
         let description = rule.description();
+        let change = violation.change();
+        let property = change.path.clone().unwrap_or_default();
 
         // Map rule names to emojis and extract details
         let (emoji, details) = match rule_name {
@@ -766,7 +662,7 @@ impl HtmlRenderer {
                 vec![PropertyCard {
                     emoji: "🔧".to_string(),
                     property_type: "Required".to_string(),
-                    content: description.clone(),
+                    content: property.clone(),
                 }],
             ),
             "RequiredPropertyRemoved" => (
@@ -774,14 +670,13 @@ impl HtmlRenderer {
                 vec![PropertyCard {
                     emoji: "🔧".to_string(),
                     property_type: "Optional".to_string(),
-                    content: description.clone(),
+                    content: property.clone(),
                 }],
             ),
             "PropertyAdded" => ("🔧", vec![]),
             "PropertyRemoved" => ("🔧", vec![]),
             "DescriptionChanged" => ("📄", vec![]),
-            "EnumValuesAdded" => ("➕", vec![]),
-            "EnumValuesRemoved" => ("➖", vec![]),
+            "EnumChanged" => ("🔢", vec![]),
             "FormatChanged" => ("🏷️", vec![]),
             "NullableChanged" => ("❓", vec![]),
             "ArrayItemsChanged" => ("📦", vec![]),
@@ -790,6 +685,7 @@ impl HtmlRenderer {
             "RouteRemoved" => ("➖", vec![]),
             "RouteDescriptionChanged" => ("📄", vec![]),
             "RouteSummaryChanged" => ("📝", vec![]),
+            "RouteDeprecated" => ("⏳", vec![]),
             "RequiredParameterAdded" => ("⚠️", vec![]),
             "ParameterRemoved" => ("⚠️", vec![]),
             "ResponseStatusAdded" => ("➕", vec![]),
@@ -806,6 +702,8 @@ impl HtmlRenderer {
         };
 
         DifferenceData {
+            id: change.fingerprint(subject),
+            node: change.node().cloned(),
             emoji: emoji.to_string(),
             description,
             change_level,
@@ -816,16 +714,207 @@ impl HtmlRenderer {
 }
 
 impl Renderer for HtmlRenderer {
-    fn render(&self, results: &[MatchResult]) -> Result<String, Box<dyn Error>> {
-        let data = self.convert_to_template_data(results);
-        let mut context = Context::new();
-        context.insert("data", &data);
-
-        let html = self.tera.render("report.html", &context)?;
-        Ok(html)
+    fn render(&self, report: &Report) -> Result<String, Box<dyn Error>> {
+        self.render_with_routes(
+            report.schema_results,
+            report.route_results,
+            report.route_infos,
+            report.event_results,
+            report.event_infos,
+            report.full_schema_infos,
+        )
     }
 
     fn file_extension(&self) -> &str {
         "html"
     }
 }
+
+/// Collect templates under `dir` recursively, named by their path relative to `root`.
+/// Hidden files and files that aren't UTF-8 text, e.g. images, are skipped
+fn collect_templates(
+    root: &Path,
+    dir: &Path,
+    templates: &mut Vec<(String, String)>,
+) -> Result<(), String> {
+    let read_error = |path: &Path, err: std::io::Error| {
+        format!("Failed to read templates from {}: {}", path.display(), err)
+    };
+    let mut entries = fs::read_dir(dir)
+        .and_then(|entries| entries.collect::<Result<Vec<_>, _>>())
+        .map_err(|err| read_error(dir, err))?;
+    entries.sort_by_key(|entry| entry.path());
+
+    for entry in entries {
+        let path = entry.path();
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        if path.is_dir() {
+            collect_templates(root, &path, templates)?;
+            continue;
+        }
+
+        let bytes = fs::read(&path).map_err(|err| read_error(&path, err))?;
+        let Ok(content) = String::from_utf8(bytes) else {
+            continue;
+        };
+        let name = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        templates.push((name, content));
+    }
+    Ok(())
+}
+
+/// Tera error with its causes, the top-level message alone doesn't say what went wrong
+fn describe_tera_error(err: &tera::Error) -> String {
+    let mut message = err.to_string();
+    let mut source = err.source();
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::schema::SchemaRemovedRule;
+    use crate::rules::RuleViolation;
+    use std::path::PathBuf;
+
+    /// Empty directory for the templates of one test
+    fn template_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("apidrift-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("components")).unwrap();
+        dir
+    }
+
+    fn render(renderer: &HtmlRenderer) -> Result<String, Box<dyn Error>> {
+        let schema_results = vec![MatchResult::new(
+            "Legacy".to_string(),
+            vec![RuleViolation::new(Box::new(SchemaRemovedRule {
+                schema_name: "Legacy".to_string(),
+            }))],
+        )];
+        renderer.render_with_routes(&schema_results, &[], &[], &[], &[], &[])
+    }
+
+    #[test]
+    fn test_template_dir_overrides_embedded_templates() {
+        let dir = template_dir("override");
+        fs::write(
+            dir.join("components/header.html"),
+            "<header>ACME API changes</header>",
+        )
+        .unwrap();
+
+        let html = render(
+            &HtmlRenderer::new()
+                .unwrap()
+                .with_template_dir(&dir)
+                .unwrap(),
+        )
+        .unwrap();
+        assert!(html.contains("<header>ACME API changes</header>"));
+        // Other components still come from the embedded templates
+        assert!(html.contains("Legacy"));
+
+        fs::write(
+            dir.join("report.html"),
+            "v{{ context_version }}: {{ data.stats.breaking_changes }} breaking in {{ data.schemas.0.name | safe }}",
+        )
+        .unwrap();
+        let text = render(
+            &HtmlRenderer::new()
+                .unwrap()
+                .with_template_dir(&dir)
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(text, "v1: 1 breaking in Legacy");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_template_dir_errors() {
+        let dir = template_dir("errors");
+        assert!(HtmlRenderer::new()
+            .unwrap()
+            .with_template_dir(&dir)
+            .is_err());
+
+        fs::write(dir.join("report.html"), "{{ data.missing.field }}").unwrap();
+        let renderer = HtmlRenderer::new()
+            .unwrap()
+            .with_template_dir(&dir)
+            .unwrap();
+        let err = render(&renderer).unwrap_err().to_string();
+        assert!(err.contains("data.missing.field"), "{}", err);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_template_dir_skips_hidden_and_binary_files() {
+        let dir = template_dir("assets");
+        fs::write(dir.join("report.html"), "custom").unwrap();
+        fs::write(dir.join("logo.png"), [0x89, b'P', b'N', b'G', 0xff, 0xfe]).unwrap();
+        fs::write(dir.join(".DS_Store"), [0x00, 0x00, 0x00, 0x01, 0xff]).unwrap();
+        fs::write(dir.join(".notes.html"), "{{ unclosed").unwrap();
+
+        let renderer = HtmlRenderer::new()
+            .unwrap()
+            .with_template_dir(&dir)
+            .unwrap();
+        assert_eq!(render(&renderer).unwrap(), "custom");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_removed_callback_keeps_its_method_and_path() {
+        let spec = |callbacks: &str| {
+            let content = format!(
+                r#"
+openapi: 3.0.3
+info: {{title: Test, version: "1"}}
+paths:
+  /subscriptions:
+    post:
+      responses:
+        "201": {{description: Subscribed}}
+      callbacks: {callbacks}
+"#
+            );
+            crate::loader::parse_spec(&content, std::path::Path::new("openapi.yaml"))
+                .unwrap()
+                .0
+        };
+        let base = spec(
+            r#"{onPaid: {"{$request.body#/url}": {post: {responses: {"200": {description: OK}}}}}}"#,
+        );
+        let current = spec("{}");
+
+        let matcher = crate::matcher::RouteMatcher::new(&base, &current);
+        let event_results = matcher.match_event_routes_with_schema_violations(&[]);
+        let data = HtmlRenderer::new()
+            .unwrap()
+            .convert_to_template_data_with_routes(&[], &[], &[], &event_results, &[], &[]);
+
+        let route = &data.event_routes[0];
+        assert_eq!(route.method, "POST");
+        assert_eq!(route.path, "{$request.body#/url}");
+        assert_eq!(
+            route.kind_label.as_deref(),
+            Some("Callback 'onPaid' of POST /subscriptions")
+        );
+    }
+}
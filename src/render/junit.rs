@@ -0,0 +1,195 @@
+use crate::render::{Renderer, Report};
+use crate::rules::MatchResult;
+use crate::ChangeLevel;
+use std::error::Error;
+use std::fmt::Write;
+
+/// Renders a JUnit XML test report for CI systems that only show test results.
+///
+/// Every schema and route with changes is a testcase, failed when it has breaking changes.
+/// Other changes are listed in the testcase output.
+pub struct JunitRenderer;
+
+impl JunitRenderer {
+    fn write_suite(xml: &mut String, name: &str, classname: &str, results: &[MatchResult]) {
+        if results.is_empty() {
+            return;
+        }
+
+        let failures = results
+            .iter()
+            .filter(|result| result.change_level == ChangeLevel::Breaking)
+            .count();
+        let _ = writeln!(
+            xml,
+            r#"  <testsuite name="{}" tests="{}" failures="{}" errors="0" skipped="0">"#,
+            escape(name),
+            results.len(),
+            failures
+        );
+
+        let mut results: Vec<&MatchResult> = results.iter().collect();
+        results.sort_by(|a, b| a.name.cmp(&b.name));
+        for result in results {
+            Self::write_testcase(xml, classname, result);
+        }
+
+        xml.push_str("  </testsuite>\n");
+    }
+
+    fn write_testcase(xml: &mut String, classname: &str, result: &MatchResult) {
+        let _ = writeln!(
+            xml,
+            r#"    <testcase classname="{}" name="{}">"#,
+            escape(classname),
+            escape(&result.name)
+        );
+
+        let (breaking, other): (Vec<_>, Vec<_>) = result
+            .violations
+            .iter()
+            .map(|violation| {
+                let change = violation.change();
+                let line = format!(
+                    "[{:?}] {} (#{})",
+                    change.level,
+                    change.description,
+                    change.fingerprint(&result.name)
+                );
+                (change.level, line)
+            })
+            .partition(|(level, _)| *level == ChangeLevel::Breaking);
+
+        if !breaking.is_empty() {
+            let message = match breaking.as_slice() {
+                [(_, line)] => line.clone(),
+                _ => format!("{} breaking changes", breaking.len()),
+            };
+            let lines: Vec<&str> = breaking.iter().map(|(_, line)| line.as_str()).collect();
+            let _ = writeln!(
+                xml,
+                r#"      <failure message="{}" type="BreakingChange">{}</failure>"#,
+                escape(&message),
+                escape(&lines.join("\n"))
+            );
+        }
+
+        if !other.is_empty() {
+            let lines: Vec<&str> = other.iter().map(|(_, line)| line.as_str()).collect();
+            let _ = writeln!(
+                xml,
+                "      <system-out>{}</system-out>",
+                escape(&lines.join("\n"))
+            );
+        }
+
+        xml.push_str("    </testcase>\n");
+    }
+}
+
+impl Renderer for JunitRenderer {
+    fn render(&self, report: &Report) -> Result<String, Box<dyn Error>> {
+        let suites = [
+            ("Schemas", "apidrift.schemas", report.schema_results),
+            ("Routes", "apidrift.routes", report.route_results),
+            (
+                "Webhooks and callbacks",
+                "apidrift.events",
+                report.event_results,
+            ),
+        ];
+
+        let results = suites.iter().flat_map(|(_, _, results)| results.iter());
+        let tests = results.clone().count();
+        let failures = results
+            .filter(|result| result.change_level == ChangeLevel::Breaking)
+            .count();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            r#"<testsuites name="apidrift" tests="{}" failures="{}" errors="0">"#,
+            tests, failures
+        );
+        for (name, classname, results) in suites {
+            Self::write_suite(&mut xml, name, classname, results);
+        }
+        xml.push_str("</testsuites>\n");
+
+        Ok(xml)
+    }
+
+    fn file_extension(&self) -> &str {
+        "xml"
+    }
+}
+
+/// Escape text for XML attributes and content
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters are not allowed in XML 1.0
+            c if c.is_control() && !matches!(c, '\n' | '\r' | '\t') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::schema::{PropertyAddedRule, SchemaRemovedRule};
+    use crate::rules::RuleViolation;
+
+    #[test]
+    fn test_junit_report() {
+        let schema_results = vec![
+            MatchResult::new(
+                "Legacy".to_string(),
+                vec![RuleViolation::new(Box::new(SchemaRemovedRule {
+                    schema_name: "Legacy".to_string(),
+                }))],
+            ),
+            MatchResult::new(
+                "User".to_string(),
+                vec![RuleViolation::new(Box::new(PropertyAddedRule {
+                    schema_name: "User".to_string(),
+                    property_path: String::new(),
+                    property_name: "<nick>".to_string(),
+                }))],
+            ),
+        ];
+        let report = Report {
+            schema_results: &schema_results,
+            route_results: &[],
+            route_infos: &[],
+            event_results: &[],
+            event_infos: &[],
+            full_schema_infos: &[],
+            current_file: None,
+            base_version: "1.0.0",
+            current_version: "1.0.0",
+        };
+
+        let xml = JunitRenderer.render(&report).unwrap();
+        assert!(xml.contains(r#"<testsuites name="apidrift" tests="2" failures="1" errors="0">"#));
+        assert!(xml.contains(r#"<testsuite name="Schemas" tests="2" failures="1""#));
+        assert!(!xml.contains("Routes"));
+
+        let legacy =
+            &xml[xml.find(r#"name="Legacy""#).unwrap()..xml.find(r#"name="User""#).unwrap()];
+        assert!(legacy
+            .contains(r#"<failure message="[Breaking] Schema &apos;Legacy&apos; was removed"#));
+
+        let user = &xml[xml.find(r#"name="User""#).unwrap()..];
+        assert!(!user.contains("<failure"));
+        assert!(user.contains("&lt;nick&gt;"));
+    }
+}
@@ -0,0 +1,233 @@
+use crate::render::{display_path, rule_title, Renderer, Report, ReportedChange};
+use crate::ChangeLevel;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::path::Path;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Key of change IDs in `partialFingerprints`, versioned in case the fingerprint changes
+const FINGERPRINT_KEY: &str = "apidriftChangeId/v1";
+
+/// Renders changes as a SARIF 2.1.0 log for code scanning tools.
+///
+/// Every rule becomes a `reportingDescriptor` and every change a `result` located in the
+/// current spec file.
+pub struct SarifRenderer;
+
+impl SarifRenderer {
+    fn rules(changes: &[ReportedChange]) -> BTreeMap<&str, Value> {
+        let mut rules = BTreeMap::new();
+        for reported in changes {
+            let change = &reported.change;
+            rules.entry(change.rule.as_str()).or_insert_with(|| {
+                json!({
+                    "id": change.rule,
+                    "name": change.rule,
+                    "shortDescription": {"text": rule_title(&change.rule)},
+                    "properties": {"category": change.category},
+                })
+            });
+        }
+        rules
+    }
+
+    fn result(reported: &ReportedChange, rule_index: usize, current_file: Option<&Path>) -> Value {
+        let change = &reported.change;
+
+        let mut location = json!({});
+        if let Some(file) = current_file {
+            let mut physical = json!({"artifactLocation": {"uri": display_path(file)}});
+            if let Some(source) = change.current_node.as_ref().and_then(|node| node.source) {
+                physical["region"] = json!({
+                    "startLine": source.line,
+                    "startColumn": source.column,
+                });
+            }
+            location["physicalLocation"] = physical;
+        }
+        if let Some(node) = &change.current_node {
+            location["logicalLocations"] = json!([{
+                "name": reported.subject,
+                "fullyQualifiedName": node.pointer,
+            }]);
+        }
+
+        json!({
+            "ruleId": change.rule,
+            "ruleIndex": rule_index,
+            "level": level(&change.level),
+            "message": {"text": change.description},
+            "locations": [location],
+            "partialFingerprints": {FINGERPRINT_KEY: reported.id},
+            "properties": {
+                "subject": reported.subject,
+                "change": change,
+            },
+        })
+    }
+}
+
+impl Renderer for SarifRenderer {
+    fn render(&self, report: &Report) -> Result<String, Box<dyn Error>> {
+        let changes = report.changes();
+        let rules = Self::rules(&changes);
+        let rule_indexes: BTreeMap<&str, usize> = rules
+            .keys()
+            .enumerate()
+            .map(|(index, name)| (*name, index))
+            .collect();
+
+        let results: Vec<Value> = changes
+            .iter()
+            .map(|reported| {
+                let rule_index = rule_indexes[reported.change.rule.as_str()];
+                Self::result(reported, rule_index, report.current_file)
+            })
+            .collect();
+
+        let log = json!({
+            "$schema": SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "apidrift",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": env!("CARGO_PKG_HOMEPAGE"),
+                        "rules": rules.into_values().collect::<Vec<_>>(),
+                    }
+                },
+                "results": results,
+            }],
+        });
+
+        Ok(serde_json::to_string_pretty(&log)?)
+    }
+
+    fn file_extension(&self) -> &str {
+        "sarif"
+    }
+}
+
+/// SARIF level of a change
+fn level(level: &ChangeLevel) -> &'static str {
+    match level {
+        ChangeLevel::Breaking => "error",
+        ChangeLevel::Warning => "warning",
+        ChangeLevel::Change => "note",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loader::{parse_spec, SourceMap, SpecFormat};
+    use crate::matcher::{RouteMatcher, SchemaMatcher};
+
+    const BASE: &str = "\
+openapi: 3.0.3
+info: {title: Test, version: '1'}
+paths:
+  /users:
+    get:
+      responses:
+        '200': {description: OK}
+components:
+  schemas:
+    User:
+      properties:
+        age: {type: string}
+";
+
+    #[test]
+    fn test_sarif_log() {
+        let current_content = BASE.replace("type: string", "type: integer").replace(
+            "'200': {description: OK}",
+            "'200': {description: OK}\n        '404': {description: Missing}",
+        );
+        let (base, _) = parse_spec(BASE, Path::new("base.yaml")).unwrap();
+        let (current, _) = parse_spec(&current_content, Path::new("current.yaml")).unwrap();
+        let source_maps = || {
+            (
+                SourceMap::new(BASE, SpecFormat::Yaml),
+                SourceMap::new(&current_content, SpecFormat::Yaml),
+            )
+        };
+
+        let base_schemas = &base.components.as_ref().unwrap().schemas;
+        let current_schemas = &current.components.as_ref().unwrap().schemas;
+        let (base_map, current_map) = source_maps();
+        let schema_results = SchemaMatcher::new(base_schemas, current_schemas, &base, &current)
+            .with_source_maps(base_map, current_map)
+            .match_schemas();
+        let (base_map, current_map) = source_maps();
+        let route_results = RouteMatcher::new(&base, &current)
+            .with_source_maps(base_map, current_map)
+            .match_routes_with_schema_violations(&schema_results);
+
+        let report = Report {
+            schema_results: &schema_results,
+            route_results: &route_results,
+            route_infos: &[],
+            event_results: &[],
+            event_infos: &[],
+            full_schema_infos: &[],
+            current_file: Some(Path::new("./api/current.yaml")),
+            base_version: "1.0.0",
+            current_version: "1.0.0",
+        };
+        let log: Value = serde_json::from_str(&SarifRenderer.render(&report).unwrap()).unwrap();
+
+        let run = &log["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        let results = run["results"].as_array().unwrap();
+        // The type change is reported once, not again for GET /users
+        assert_eq!(results.len(), 2);
+
+        for result in results {
+            let rule = &rules[result["ruleIndex"].as_u64().unwrap() as usize];
+            assert_eq!(rule["id"], result["ruleId"]);
+            assert_eq!(
+                result["partialFingerprints"][FINGERPRINT_KEY]
+                    .as_str()
+                    .unwrap()
+                    .len(),
+                12
+            );
+        }
+
+        let type_changed = results
+            .iter()
+            .find(|r| r["ruleId"] == "TypeChanged")
+            .unwrap();
+        assert_eq!(type_changed["level"], "error");
+        let location = &type_changed["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "api/current.yaml");
+        assert_eq!(location["region"]["startLine"], 13);
+
+        let status_added = results
+            .iter()
+            .find(|r| r["ruleId"] == "ResponseStatusAdded")
+            .unwrap();
+        assert_eq!(status_added["level"], "note");
+        assert_eq!(
+            status_added["locations"][0]["physicalLocation"]["region"]["startLine"],
+            8
+        );
+    }
+
+    #[test]
+    fn test_sarif_level_of_route_side_breaking_change() {
+        let (schema_results, route_results) =
+            crate::render::tests::response_property_made_optional();
+        let report = crate::render::tests::report(&schema_results, &route_results);
+        let log: Value = serde_json::from_str(&SarifRenderer.render(&report).unwrap()).unwrap();
+
+        let results = log["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ruleId"], "RequiredPropertyRemoved");
+        assert_eq!(results[0]["level"], "error");
+    }
+}
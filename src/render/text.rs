@@ -0,0 +1,365 @@
+use crate::matcher::RouteSideLevels;
+use crate::render::{Renderer, Report};
+use crate::rules::{ChangeLocation, MatchResult};
+use crate::ChangeLevel;
+use std::collections::BTreeMap;
+use std::error::Error;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+
+/// Width of the level tag in change lines, e.g. "breaking"
+const TAG_WIDTH: usize = 8;
+
+/// Renders a tree of changed schemas and routes for the terminal, colored by change level
+pub struct TextRenderer {
+    color: bool,
+    /// Lines are wrapped to this width, `None` to keep them whole
+    width: Option<usize>,
+}
+
+impl TextRenderer {
+    pub fn new(color: bool, width: Option<usize>) -> Self {
+        Self { color, width }
+    }
+
+    /// Renderer for output to stdout: colored when it is a terminal and `NO_COLOR` is not set,
+    /// wrapped to the terminal width
+    pub fn for_stdout() -> Self {
+        use std::io::IsTerminal;
+
+        let is_terminal = std::io::stdout().is_terminal();
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        Self::new(
+            is_terminal && !no_color,
+            is_terminal.then(terminal_width).flatten(),
+        )
+    }
+
+    fn paint(&self, style: &str, text: &str) -> String {
+        if self.color {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn level_tag(&self, level: &ChangeLevel) -> String {
+        let (style, name) = match level {
+            ChangeLevel::Breaking => ("\x1b[1;31m", "breaking"),
+            ChangeLevel::Warning => ("\x1b[33m", "warning"),
+            ChangeLevel::Change => ("\x1b[32m", "change"),
+        };
+        self.paint(style, &format!("{:<width$}", name, width = TAG_WIDTH))
+    }
+
+    fn write_section(
+        &self,
+        output: &mut String,
+        title: &str,
+        results: &[MatchResult],
+        levels: &RouteSideLevels,
+    ) {
+        if results.is_empty() {
+            return;
+        }
+
+        let mut results: Vec<&MatchResult> = results.iter().collect();
+        results.sort_by(|a, b| a.name.cmp(&b.name));
+
+        output.push_str(&self.paint(BOLD, &format!("{} ({})", title, results.len())));
+        output.push('\n');
+        for (index, result) in results.iter().enumerate() {
+            let last = index + 1 == results.len();
+            let (branch, indent) = if last {
+                ("└─ ", "   ")
+            } else {
+                ("├─ ", "│  ")
+            };
+
+            // Schema changes can be rated higher on the routes using them
+            let lines = Self::change_lines(result, levels);
+            let level =
+                lines
+                    .iter()
+                    .map(|(level, _)| level)
+                    .fold(&result.change_level, |max, level| {
+                        if level.is_at_least(max) {
+                            level
+                        } else {
+                            max
+                        }
+                    });
+
+            output.push_str(&self.paint(DIM, branch));
+            output.push_str(&format!(
+                "{}  {}\n",
+                self.paint(BOLD, &result.name),
+                self.level_tag(level).trim_end()
+            ));
+
+            for (line_index, (level, text)) in lines.iter().enumerate() {
+                let last_line = line_index + 1 == lines.len();
+                let prefix = format!("{}{}", indent, if last_line { "└─ " } else { "├─ " });
+                let continuation = format!("{}{}", indent, if last_line { "   " } else { "│  " });
+                self.write_change(output, &prefix, &continuation, level, text);
+            }
+        }
+        output.push('\n');
+    }
+
+    /// Changes of a result, with schema changes seen through a route summarized per schema
+    fn change_lines(result: &MatchResult, levels: &RouteSideLevels) -> Vec<(ChangeLevel, String)> {
+        let mut lines = Vec::new();
+        let mut schemas: BTreeMap<String, (ChangeLevel, usize)> = BTreeMap::new();
+
+        for violation in &result.violations {
+            let change = violation.change();
+            let is_schema_change = matches!(
+                violation.name(),
+                "RequestSchemaViolation" | "ResponseSchemaViolation"
+            );
+            if !is_schema_change {
+                lines.push((levels.level_of(&result.name, &change), change.description));
+                continue;
+            }
+
+            let place = match &change.location {
+                Some(ChangeLocation::Response { status_code, .. }) => {
+                    format!("response {}", status_code)
+                }
+                _ => "request body".to_string(),
+            };
+            let key = format!(
+                "Schema '{}' of the {} changed",
+                change.schema_name.unwrap_or_default(),
+                place
+            );
+            let entry = schemas.entry(key).or_insert((ChangeLevel::Change, 0));
+            if change.level.is_at_least(&entry.0) {
+                entry.0 = change.level;
+            }
+            entry.1 += 1;
+        }
+
+        for (text, (level, count)) in schemas {
+            let noun = if count == 1 { "change" } else { "changes" };
+            lines.push((level, format!("{} ({} {})", text, count, noun)));
+        }
+        lines
+    }
+
+    fn write_change(
+        &self,
+        output: &mut String,
+        prefix: &str,
+        continuation: &str,
+        level: &ChangeLevel,
+        text: &str,
+    ) {
+        let text_width = self
+            .width
+            .map(|width| width.saturating_sub(prefix.chars().count() + TAG_WIDTH + 1));
+        let wrapped = wrap(text, text_width);
+
+        for (index, line) in wrapped.iter().enumerate() {
+            if index == 0 {
+                output.push_str(&self.paint(DIM, prefix));
+                output.push_str(&self.level_tag(level));
+            } else {
+                output.push_str(&self.paint(DIM, continuation));
+                output.push_str(&" ".repeat(TAG_WIDTH));
+            }
+            output.push(' ');
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+}
+
+impl Renderer for TextRenderer {
+    fn render(&self, report: &Report) -> Result<String, Box<dyn Error>> {
+        let mut output = String::new();
+        let levels = RouteSideLevels::new(report.route_results.iter().chain(report.event_results));
+        self.write_section(&mut output, "Schemas", report.schema_results, &levels);
+        self.write_section(&mut output, "Routes", report.route_results, &levels);
+        self.write_section(
+            &mut output,
+            "Webhooks and callbacks",
+            report.event_results,
+            &levels,
+        );
+
+        let changes = report.changes();
+        if changes.is_empty() {
+            output.push_str("No changes detected\n");
+            return Ok(output);
+        }
+
+        let count = |level: ChangeLevel| {
+            changes
+                .iter()
+                .filter(|reported| reported.change.level == level)
+                .count()
+        };
+        output.push_str(&format!(
+            "{} breaking, {} warning, {} change\n",
+            self.paint("\x1b[1;31m", &count(ChangeLevel::Breaking).to_string()),
+            self.paint("\x1b[33m", &count(ChangeLevel::Warning).to_string()),
+            self.paint("\x1b[32m", &count(ChangeLevel::Change).to_string()),
+        ));
+        Ok(output)
+    }
+
+    fn file_extension(&self) -> &str {
+        "txt"
+    }
+}
+
+/// Split text into lines of at most `width` characters at spaces, words longer than the
+/// width are kept whole
+fn wrap(text: &str, width: Option<usize>) -> Vec<String> {
+    let Some(width) = width.filter(|width| *width >= 20) else {
+        return vec![text.to_string()];
+    };
+
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split(' ') {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    lines.push(line);
+    lines
+}
+
+/// Width of the terminal: `COLUMNS` when set, otherwise asked from the terminal on unix
+fn terminal_width() -> Option<usize> {
+    if let Some(columns) = std::env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+    {
+        return Some(columns);
+    }
+
+    #[cfg(unix)]
+    {
+        let mut size = libc::winsize {
+            ws_row: 0,
+            ws_col: 0,
+            ws_xpixel: 0,
+            ws_ypixel: 0,
+        };
+        // SAFETY: TIOCGWINSZ only writes a winsize into the given pointer
+        let result = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) };
+        if result == 0 && size.ws_col > 0 {
+            return Some(size.ws_col as usize);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::schema::{PropertyRemovedRule, SchemaAddedRule};
+    use crate::rules::RuleViolation;
+
+    fn report_text(renderer: &TextRenderer) -> String {
+        let schema_results = vec![
+            MatchResult::new(
+                "User".to_string(),
+                vec![RuleViolation::new(Box::new(PropertyRemovedRule {
+                    schema_name: "User".to_string(),
+                    property_path: String::new(),
+                    property_name: "name".to_string(),
+                    was_required: false,
+                    totally_removed: true,
+                }))],
+            ),
+            MatchResult::new(
+                "Address".to_string(),
+                vec![RuleViolation::new(Box::new(SchemaAddedRule {
+                    schema_name: "Address".to_string(),
+                }))],
+            ),
+        ];
+        let report = Report {
+            schema_results: &schema_results,
+            route_results: &[],
+            route_infos: &[],
+            event_results: &[],
+            event_infos: &[],
+            full_schema_infos: &[],
+            current_file: None,
+            base_version: "1.0.0",
+            current_version: "1.0.0",
+        };
+        renderer.render(&report).unwrap()
+    }
+
+    #[test]
+    fn test_plain_tree() {
+        let text = report_text(&TextRenderer::new(false, None));
+        let expected = "\
+Schemas (2)
+├─ Address  change
+│  └─ change   Schema 'Address' was added
+└─ User  breaking
+   └─ breaking Property 'name' was removed
+
+1 breaking, 0 warning, 1 change
+";
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn test_colors_and_width() {
+        let text = report_text(&TextRenderer::new(true, Some(40)));
+        assert!(text.contains("\x1b[1;31mbreaking\x1b[0m"));
+
+        let lines: Vec<&str> = text.lines().collect();
+        let wrapped = lines
+            .iter()
+            .position(|line| line.ends_with("Schema 'Address' was"))
+            .unwrap();
+        assert!(lines[wrapped + 1].ends_with("         added"));
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("a b", None), vec!["a b"]);
+        assert_eq!(
+            wrap("one two three four five six", Some(20)),
+            vec!["one two three four", "five six"]
+        );
+    }
+
+    #[test]
+    fn test_summary_counts_route_side_levels() {
+        let (schema_results, route_results) =
+            crate::render::tests::response_property_made_optional();
+        let report = crate::render::tests::report(&schema_results, &route_results);
+
+        let text = TextRenderer::new(false, None).render(&report).unwrap();
+        let expected = "\
+Schemas (1)
+└─ User  breaking
+   └─ breaking Required property 'name' was removed
+
+Routes (1)
+└─ GET /users  breaking
+   └─ breaking Schema 'User' of the response 200 changed (1 change)
+
+1 breaking, 0 warning, 0 change
+";
+        assert_eq!(text, expected);
+    }
+}
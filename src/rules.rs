@@ -1,10 +1,14 @@
 pub mod route;
 pub mod schema;
+pub mod type_name;
 
+use crate::loader::SourceLocation;
+use crate::matcher::RouteInfo;
 use crate::ChangeLevel;
 
 /// Anchor point for a change in the schema structure
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "kind", content = "path")]
 pub enum ChangeAnchor {
     /// Change at the schema level itself (added/removed schema)
     Schema,
@@ -77,10 +81,147 @@ pub trait Rule: std::fmt::Debug {
     fn category(&self) -> RuleCategory {
         RuleCategory::Schema
     }
+
+    /// Optional: which messages the changed property appears in (readOnly/writeOnly)
+    fn visibility(&self) -> Visibility {
+        Visibility::Both
+    }
+
+    /// Structured form of this violation, rules add their schema and old/new values
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+    }
+}
+
+/// Where in a route a change was found
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "in", rename_all = "snake_case")]
+pub enum ChangeLocation {
+    /// The operation itself, e.g. its summary
+    Operation,
+    Parameter {
+        name: String,
+        location: String,
+    },
+    RequestBody {
+        content_type: String,
+    },
+    Response {
+        status_code: String,
+        content_type: Option<String>,
+    },
+}
+
+/// Length of change fingerprints in bytes, they are shown as twice as many hex digits
+const FINGERPRINT_BYTES: usize = 6;
+
+/// Serializable rule violation with typed old/new values, for renderers and downstream tools
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct Change {
+    /// Rule name, e.g. "TypeChanged"
+    pub rule: String,
+    pub description: String,
+    pub level: ChangeLevel,
+    pub category: RuleCategory,
+    pub anchor: ChangeAnchor,
+    /// Schema the change was found in
+    pub schema_name: Option<String>,
+    /// Property path inside the schema, e.g. "address.street"
+    pub path: Option<String>,
+    /// Part of the route, for route changes and schema changes seen through a route
+    pub location: Option<ChangeLocation>,
+    pub old_value: Option<serde_json::Value>,
+    pub new_value: Option<serde_json::Value>,
+    /// Node behind the change in the base version
+    pub base_node: Option<NodeLocation>,
+    /// Node behind the change in the current version
+    pub current_node: Option<NodeLocation>,
+}
+
+impl Change {
+    /// Change with the fields every rule has, the path is taken from the anchor
+    pub fn from_rule<R: Rule + ?Sized>(rule: &R) -> Self {
+        let anchor = rule.context();
+        Self {
+            rule: rule.name().to_string(),
+            description: rule.description(),
+            level: rule.change_level(),
+            category: rule.category(),
+            path: anchor.property_path().map(|path| path.to_string()),
+            anchor,
+            schema_name: None,
+            location: None,
+            old_value: None,
+            new_value: None,
+            base_node: None,
+            current_node: None,
+        }
+    }
+
+    pub fn in_schema(mut self, schema_name: &str) -> Self {
+        self.schema_name = Some(schema_name.to_string());
+        self
+    }
+
+    pub fn at_path(mut self, path: String) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    pub fn at(mut self, location: ChangeLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Node to point readers at: in the current version, or in the base one for removals
+    pub fn node(&self) -> Option<&NodeLocation> {
+        self.current_node.as_ref().or(self.base_node.as_ref())
+    }
+
+    /// Stable ID of this change within `subject`, the schema or route it was reported for.
+    ///
+    /// Hashes the rule name, subject and where the change is anchored, so the same change
+    /// gets the same ID across runs while descriptions and levels may evolve.
+    pub fn fingerprint(&self, subject: &str) -> String {
+        use sha2::{Digest, Sha256};
+
+        let anchor = serde_json::json!([self.anchor, self.schema_name, self.path, self.location]);
+        let mut hasher = Sha256::new();
+        for part in [self.rule.as_str(), subject, &anchor.to_string()] {
+            hasher.update(part.as_bytes());
+            // Separator, so that parts can't run into each other
+            hasher.update([0]);
+        }
+
+        hasher.finalize()[..FINGERPRINT_BYTES]
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    pub fn with_values<O: serde::Serialize, N: serde::Serialize>(
+        mut self,
+        old_value: Option<O>,
+        new_value: Option<N>,
+    ) -> Self {
+        self.old_value = old_value.and_then(|value| serde_json::to_value(value).ok());
+        self.new_value = new_value.and_then(|value| serde_json::to_value(value).ok());
+        self
+    }
+}
+
+/// Node of one version of the specification, see [`crate::locate::SpecLocator`]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct NodeLocation {
+    /// JSON pointer into the loaded document, e.g. "/components/schemas/User/properties/name"
+    pub pointer: String,
+    /// Line and column in the source file, when it is known
+    #[serde(flatten)]
+    pub source: Option<SourceLocation>,
 }
 
 /// Category of rule to support different API aspects
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
 pub enum RuleCategory {
     Schema,
     Endpoint,
@@ -89,6 +230,144 @@ pub enum RuleCategory {
     RequestBody,
 }
 
+/// Messages a property appears in, from `readOnly` and `writeOnly`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Visibility {
+    #[default]
+    Both,
+    /// Only sent by the API (`readOnly`)
+    ReadOnly,
+    /// Only sent to the API (`writeOnly`)
+    WriteOnly,
+}
+
+impl Visibility {
+    pub fn of(schema: &oas3::spec::ObjectSchema) -> Self {
+        if schema.read_only == Some(true) {
+            Visibility::ReadOnly
+        } else if schema.write_only == Some(true) {
+            Visibility::WriteOnly
+        } else {
+            Visibility::Both
+        }
+    }
+
+    /// Visibility shared by both versions of a schema, `Both` if it was toggled
+    pub fn shared(
+        base: Option<&oas3::spec::ObjectSchema>,
+        current: Option<&oas3::spec::ObjectSchema>,
+    ) -> Self {
+        match (base.map(Self::of), current.map(Self::of)) {
+            (Some(base), Some(current)) if base == current => base,
+            (Some(visibility), None) | (None, Some(visibility)) => visibility,
+            _ => Visibility::Both,
+        }
+    }
+
+    /// This visibility if it is restricted, otherwise the given one
+    pub fn or(self, other: Visibility) -> Self {
+        if self == Visibility::Both {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Whether a property with this visibility appears on the given side of a route
+    pub fn applies_to(&self, direction: RouteDirection, side: RouteSide) -> bool {
+        match self {
+            Visibility::Both => true,
+            Visibility::ReadOnly => !direction.sent_by_consumer(side),
+            Visibility::WriteOnly => direction.sent_by_consumer(side),
+        }
+    }
+}
+
+/// Who sends the requests of a route, which decides who a change affects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RouteDirection {
+    /// Clients call the API (regular paths)
+    #[default]
+    Incoming,
+    /// The API calls consumer servers (webhooks and callbacks)
+    Outgoing,
+}
+
+/// Part of a route exchange a change belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteSide {
+    Request,
+    Response,
+}
+
+impl RouteSide {
+    /// Side of a route violation with the given category
+    pub fn of(category: &RuleCategory) -> Self {
+        match category {
+            RuleCategory::Response => RouteSide::Response,
+            _ => RouteSide::Request,
+        }
+    }
+}
+
+impl RouteDirection {
+    /// Change level of a rule violation for consumers of a route with this direction.
+    ///
+    /// Rule levels are defined for incoming routes. On outgoing routes consumers receive
+    /// the requests and send the responses, so some levels are swapped.
+    pub fn change_level(
+        &self,
+        side: RouteSide,
+        rule_name: &str,
+        level: ChangeLevel,
+    ) -> ChangeLevel {
+        let sent = self.sent_by_consumer(side);
+        match (self, side, rule_name) {
+            // Only senders of a closed object are affected
+            (_, _, "AdditionalPropertiesClosed") if !sent => ChangeLevel::Change,
+            // Consumers keep receiving properties that became readOnly
+            (_, _, "ReadOnlyChanged") if !sent => ChangeLevel::Change,
+            // Consumers may keep sending properties that became writeOnly
+            (_, _, "WriteOnlyChanged") if sent => ChangeLevel::Change,
+            // Only senders must provide new required values, receivers simply get them
+            (_, _, "RequiredPropertyAdded" | "RequiredParameterAdded") if !sent => {
+                ChangeLevel::Change
+            }
+            // Receivers may rely on values that are no longer guaranteed
+            (_, _, "RequiredPropertyRemoved" | "PropertyRemoved" | "ParameterRemoved") if !sent => {
+                ChangeLevel::Breaking
+            }
+            (RouteDirection::Incoming, _, _) => level,
+            // Consumers still answering with a removed status now break the contract
+            (RouteDirection::Outgoing, RouteSide::Response, "ResponseStatusRemoved") => {
+                ChangeLevel::Breaking
+            }
+            // Properties consumers keep sending are simply ignored
+            (
+                RouteDirection::Outgoing,
+                RouteSide::Response,
+                "PropertyRemoved" | "RequiredPropertyRemoved",
+            ) => ChangeLevel::Change,
+            // Consumers may not handle values they didn't know about
+            (RouteDirection::Outgoing, RouteSide::Request, "EnumChanged")
+                if level == ChangeLevel::Change =>
+            {
+                ChangeLevel::Warning
+            }
+            _ => level,
+        }
+    }
+
+    /// Whether consumers send the given side of the exchange
+    pub fn sent_by_consumer(&self, side: RouteSide) -> bool {
+        matches!(
+            (self, side),
+            (RouteDirection::Incoming, RouteSide::Request)
+                | (RouteDirection::Outgoing, RouteSide::Response)
+        )
+    }
+}
+
 /// Wrapper for dynamic rule violations
 #[derive(Debug)]
 pub struct RuleViolation {
@@ -123,6 +402,14 @@ impl RuleViolation {
     pub fn category(&self) -> RuleCategory {
         self.rule.category()
     }
+
+    pub fn visibility(&self) -> Visibility {
+        self.rule.visibility()
+    }
+
+    pub fn change(&self) -> Change {
+        self.rule.change()
+    }
 }
 
 /// Full schema information with all properties
@@ -130,6 +417,8 @@ impl RuleViolation {
 pub struct FullSchemaInfo {
     pub name: String,
     pub description: Option<String>,
+    /// Original file of a schema bundled from an external `$ref`
+    pub source: Option<String>,
     pub properties: Vec<SchemaProperty>,
     pub schema_level_violations: Vec<ViolationInfo>,
     pub change_level: String,
@@ -153,6 +442,10 @@ pub struct SchemaProperty {
 /// Light weight violation info for serialization
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct ViolationInfo {
+    /// Stable fingerprint of the change, see [`Change::fingerprint`]
+    pub id: String,
+    /// Node behind the change, see [`Change::node`]
+    pub node: Option<NodeLocation>,
     pub rule_name: String,
     pub description: String,
     pub change_level: String,
@@ -165,6 +458,8 @@ pub struct MatchResult {
     pub name: String,
     pub violations: Vec<RuleViolation>,
     pub change_level: ChangeLevel,
+    /// Method, path and kind of the route, `None` for schema results
+    pub route: Option<RouteInfo>,
 }
 
 impl MatchResult {
@@ -174,8 +469,15 @@ impl MatchResult {
             name,
             violations,
             change_level,
+            route: None,
         }
     }
+
+    /// Mark the result as a route result
+    pub fn with_route(mut self, route: RouteInfo) -> Self {
+        self.route = Some(route);
+        self
+    }
 }
 
 /// Calculate the overall change level from a list of violations
@@ -1,4 +1,4 @@
-use crate::rules::{Rule, RuleCategory};
+use crate::rules::{Change, ChangeLocation, Rule, RuleCategory};
 use crate::ChangeLevel;
 use oas3::spec::ObjectOrReference::Object;
 use oas3::spec::Operation;
@@ -46,6 +46,10 @@ impl Rule for RouteAddedRule {
     fn category(&self) -> RuleCategory {
         RuleCategory::Endpoint
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self).at(ChangeLocation::Operation)
+    }
 }
 
 impl RouteRule for RouteAddedRule {
@@ -97,6 +101,10 @@ impl Rule for RouteRemovedRule {
     fn category(&self) -> RuleCategory {
         RuleCategory::Endpoint
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self).at(ChangeLocation::Operation)
+    }
 }
 
 impl RouteRule for RouteRemovedRule {
@@ -150,6 +158,12 @@ impl Rule for RouteDescriptionChangedRule {
     fn category(&self) -> RuleCategory {
         RuleCategory::Endpoint
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .at(ChangeLocation::Operation)
+            .with_values(Some(&self.old_description), Some(&self.new_description))
+    }
 }
 
 impl RouteRule for RouteDescriptionChangedRule {
@@ -213,6 +227,12 @@ impl Rule for RouteSummaryChangedRule {
     fn category(&self) -> RuleCategory {
         RuleCategory::Endpoint
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .at(ChangeLocation::Operation)
+            .with_values(Some(&self.old_summary), Some(&self.new_summary))
+    }
 }
 
 impl RouteRule for RouteSummaryChangedRule {
@@ -246,6 +266,68 @@ impl RouteRule for RouteSummaryChangedRule {
     }
 }
 
+/// Rule: Route was marked as deprecated
+#[derive(Debug, Clone)]
+pub struct RouteDeprecatedRule {
+    pub path: String,
+    pub method: String,
+}
+
+impl Rule for RouteDeprecatedRule {
+    fn name(&self) -> &str {
+        "RouteDeprecated"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Route Deprecated: {} {}",
+            self.method.to_uppercase(),
+            self.path
+        )
+    }
+
+    fn change_level(&self) -> ChangeLevel {
+        // Still works, but clients should plan to move away from it
+        ChangeLevel::Warning
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        crate::rules::ChangeAnchor::Route
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Endpoint
+    }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .at(ChangeLocation::Operation)
+            .with_values(Some(false), Some(true))
+    }
+}
+
+impl RouteRule for RouteDeprecatedRule {
+    fn detect(
+        path: &str,
+        method: &str,
+        base: Option<&Operation>,
+        current: Option<&Operation>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            (Some(base_op), Some(current_op))
+                if !base_op.deprecated.unwrap_or(false)
+                    && current_op.deprecated.unwrap_or(false) =>
+            {
+                vec![Self {
+                    path: path.to_string(),
+                    method: method.to_string(),
+                }]
+            }
+            _ => vec![],
+        }
+    }
+}
+
 /// Rule: Required parameter added
 #[derive(Debug, Clone)]
 pub struct RequiredParameterAddedRule {
@@ -278,6 +360,13 @@ impl Rule for RequiredParameterAddedRule {
     fn category(&self) -> RuleCategory {
         RuleCategory::Parameter
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self).at(ChangeLocation::Parameter {
+            name: self.parameter_name.clone(),
+            location: self.parameter_in.clone(),
+        })
+    }
 }
 
 impl RouteRule for RequiredParameterAddedRule {
@@ -358,6 +447,13 @@ impl Rule for ParameterRemovedRule {
     fn category(&self) -> RuleCategory {
         RuleCategory::Parameter
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self).at(ChangeLocation::Parameter {
+            name: self.parameter_name.clone(),
+            location: self.parameter_in.clone(),
+        })
+    }
 }
 
 impl RouteRule for ParameterRemovedRule {
@@ -434,6 +530,13 @@ impl Rule for ResponseStatusAddedRule {
     fn category(&self) -> RuleCategory {
         RuleCategory::Response
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self).at(ChangeLocation::Response {
+            status_code: self.status_code.clone(),
+            content_type: None,
+        })
+    }
 }
 
 impl RouteRule for ResponseStatusAddedRule {
@@ -449,7 +552,7 @@ impl RouteRule for ResponseStatusAddedRule {
 
                 if let Some(current_responses) = &current_op.responses {
                     if let Some(base_responses) = &base_op.responses {
-                        for (status_code, _) in current_responses {
+                        for status_code in current_responses.keys() {
                             if !base_responses.contains_key(status_code) {
                                 rules.push(Self {
                                     path: path.to_string(),
@@ -496,6 +599,13 @@ impl Rule for ResponseStatusRemovedRule {
     fn category(&self) -> RuleCategory {
         RuleCategory::Response
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self).at(ChangeLocation::Response {
+            status_code: self.status_code.clone(),
+            content_type: None,
+        })
+    }
 }
 
 impl RouteRule for ResponseStatusRemovedRule {
@@ -511,7 +621,7 @@ impl RouteRule for ResponseStatusRemovedRule {
 
                 if let Some(base_responses) = &base_op.responses {
                     if let Some(current_responses) = &current_op.responses {
-                        for (status_code, _) in base_responses {
+                        for status_code in base_responses.keys() {
                             if !current_responses.contains_key(status_code) {
                                 rules.push(Self {
                                     path: path.to_string(),
@@ -535,6 +645,7 @@ impl RouteRule for ResponseStatusRemovedRule {
 pub struct RequestSchemaChangedRule {
     pub path: String,
     pub method: String,
+    pub old_schema_name: String,
     pub schema_name: String,
     pub content_type: String,
 }
@@ -559,6 +670,15 @@ impl Rule for RequestSchemaChangedRule {
     fn category(&self) -> RuleCategory {
         RuleCategory::RequestBody
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .at(ChangeLocation::RequestBody {
+                content_type: self.content_type.clone(),
+            })
+            .with_values(Some(&self.old_schema_name), Some(&self.schema_name))
+    }
 }
 
 impl RouteRule for RequestSchemaChangedRule {
@@ -583,6 +703,7 @@ impl RouteRule for RequestSchemaChangedRule {
                             rules.push(Self {
                                 path: path.to_string(),
                                 method: method.to_string(),
+                                old_schema_name: base_schema_name.clone(),
                                 schema_name: schema_name.clone(),
                                 content_type: content_type.clone(),
                             });
@@ -601,13 +722,11 @@ impl RequestSchemaChangedRule {
     fn extract_request_schemas(op: &Operation) -> std::collections::HashMap<String, String> {
         let mut schemas = std::collections::HashMap::new();
 
-        if let Some(request_body) = &op.request_body {
-            if let oas3::spec::ObjectOrReference::Object(body) = request_body {
-                for (content_type, media_type) in &body.content {
-                    if let Some(schema) = &media_type.schema {
-                        if let Some(schema_name) = Self::extract_schema_name(schema) {
-                            schemas.insert(content_type.clone(), schema_name);
-                        }
+        if let Some(oas3::spec::ObjectOrReference::Object(body)) = &op.request_body {
+            for (content_type, media_type) in &body.content {
+                if let Some(schema) = &media_type.schema {
+                    if let Some(schema_name) = Self::extract_schema_name(schema) {
+                        schemas.insert(content_type.clone(), schema_name);
                     }
                 }
             }
@@ -633,6 +752,7 @@ impl RequestSchemaChangedRule {
 pub struct ResponseSchemaChangedRule {
     pub path: String,
     pub method: String,
+    pub old_schema_name: String,
     pub schema_name: String,
     pub content_type: String,
     pub status_code: String,
@@ -661,6 +781,16 @@ impl Rule for ResponseSchemaChangedRule {
     fn category(&self) -> RuleCategory {
         RuleCategory::Response
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .at(ChangeLocation::Response {
+                status_code: self.status_code.clone(),
+                content_type: Some(self.content_type.clone()),
+            })
+            .with_values(Some(&self.old_schema_name), Some(&self.schema_name))
+    }
 }
 
 impl RouteRule for ResponseSchemaChangedRule {
@@ -687,6 +817,7 @@ impl RouteRule for ResponseSchemaChangedRule {
                             rules.push(Self {
                                 path: path.to_string(),
                                 method: method.to_string(),
+                                old_schema_name: base_schema_name.clone(),
                                 schema_name: schema_name.clone(),
                                 content_type: content_type.clone(),
                                 status_code: status_code.clone(),
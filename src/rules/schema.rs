@@ -1,7 +1,10 @@
-use crate::rules::Rule;
+use crate::rules::type_name::type_name;
+use crate::rules::{Change, Rule};
 use crate::ChangeLevel;
-use oas3::spec::ObjectSchema;
-use std::collections::HashSet;
+use oas3::spec::{
+    BooleanSchema, ObjectOrReference, ObjectSchema, Schema, SchemaType, SchemaTypeSet,
+};
+use std::collections::{BTreeMap, HashSet};
 
 /// Trait for schema-specific rules with internal detection logic
 pub trait SchemaRule: Rule {
@@ -39,6 +42,10 @@ impl Rule for SchemaAddedRule {
     fn context(&self) -> crate::rules::ChangeAnchor {
         crate::rules::ChangeAnchor::Schema
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self).in_schema(&self.schema_name)
+    }
 }
 
 impl SchemaRule for SchemaAddedRule {
@@ -79,6 +86,10 @@ impl Rule for SchemaRemovedRule {
     fn context(&self) -> crate::rules::ChangeAnchor {
         crate::rules::ChangeAnchor::Schema
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self).in_schema(&self.schema_name)
+    }
 }
 
 impl SchemaRule for SchemaRemovedRule {
@@ -129,6 +140,12 @@ impl Rule for TypeChangedRule {
             crate::rules::ChangeAnchor::PropertyType(self.property_path.clone())
         }
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(Some(&self.old_type), Some(&self.new_type))
+    }
 }
 
 impl SchemaRule for TypeChangedRule {
@@ -140,12 +157,13 @@ impl SchemaRule for TypeChangedRule {
     ) -> Vec<Self> {
         match (base, current) {
             (Some(base_schema), Some(current_schema)) => {
-                if base_schema.schema_type != current_schema.schema_type {
+                // Nullability is compared separately by NullableChangedRule
+                if non_null_types(base_schema) != non_null_types(current_schema) {
                     vec![TypeChangedRule {
                         schema_name: schema_name.to_string(),
                         property_path: property_path.to_string(),
-                        old_type: format!("{:?}", base_schema.schema_type),
-                        new_type: format!("{:?}", current_schema.schema_type),
+                        old_type: type_name(base_schema),
+                        new_type: type_name(current_schema),
                     }]
                 } else {
                     vec![]
@@ -185,6 +203,10 @@ impl Rule for PropertyAddedRule {
         };
         crate::rules::ChangeAnchor::Property(full_path)
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self).in_schema(&self.schema_name)
+    }
 }
 
 impl SchemaRule for PropertyAddedRule {
@@ -262,6 +284,12 @@ impl Rule for PropertyRemovedRule {
             crate::rules::ChangeAnchor::Property(full_path)
         }
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .at_path(join_path(&self.property_path, &self.property_name))
+    }
 }
 
 impl SchemaRule for PropertyRemovedRule {
@@ -325,6 +353,12 @@ impl Rule for RequiredPropertyAddedRule {
     fn context(&self) -> crate::rules::ChangeAnchor {
         crate::rules::ChangeAnchor::Required
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .at_path(join_path(&self.property_path, &self.property_name))
+    }
 }
 
 impl SchemaRule for RequiredPropertyAddedRule {
@@ -386,6 +420,12 @@ impl Rule for DescriptionChangedRule {
             crate::rules::ChangeAnchor::Description(self.property_path.clone())
         }
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(self.old_description.as_ref(), self.new_description.as_ref())
+    }
 }
 
 impl SchemaRule for DescriptionChangedRule {
@@ -413,99 +453,107 @@ impl SchemaRule for DescriptionChangedRule {
     }
 }
 
-/// Enum values added
+/// Enum values changed, with added and removed values paired up where possible
 #[derive(Debug, Clone)]
-pub struct EnumValuesAddedRule {
+pub struct EnumChangedRule {
     pub schema_name: String,
     pub property_path: String,
-    pub values: Vec<serde_json::Value>,
+    pub old_values: Vec<serde_json::Value>,
+    pub new_values: Vec<serde_json::Value>,
+    /// Added values not paired with a removed one
+    pub added: Vec<serde_json::Value>,
+    /// Removed values not paired with an added one
+    pub removed: Vec<serde_json::Value>,
+    /// Likely renamed values as (old, new)
+    pub renamed: Vec<(serde_json::Value, serde_json::Value)>,
+    /// Values written with another JSON type as (old, new), e.g. `1` and `"1"`
+    pub type_changed: Vec<(serde_json::Value, serde_json::Value)>,
 }
 
-impl Rule for EnumValuesAddedRule {
-    fn name(&self) -> &str {
-        "EnumValuesAdded"
-    }
-
-    fn description(&self) -> String {
-        let values_str = self
-            .values
-            .iter()
-            .map(|v| v.to_string())
-            .collect::<Vec<_>>()
-            .join(", ");
-        format!("Enum values added: [{}]", values_str)
-    }
-
-    fn change_level(&self) -> ChangeLevel {
-        ChangeLevel::Change
-    }
-
-    fn context(&self) -> crate::rules::ChangeAnchor {
-        if self.property_path.is_empty() {
-            crate::rules::ChangeAnchor::Schema
-        } else {
-            crate::rules::ChangeAnchor::EnumValues(self.property_path.clone())
+impl EnumChangedRule {
+    /// Text of a value regardless of its JSON type
+    fn text(value: &serde_json::Value) -> String {
+        match value {
+            serde_json::Value::String(text) => text.clone(),
+            other => other.to_string(),
         }
     }
-}
-
-impl SchemaRule for EnumValuesAddedRule {
-    fn detect(
-        schema_name: &str,
-        property_path: &str,
-        base: Option<&ObjectSchema>,
-        current: Option<&ObjectSchema>,
-    ) -> Vec<Self> {
-        match (base, current) {
-            (Some(base_schema), Some(current_schema)) => {
-                let base_values: HashSet<_> = base_schema.enum_values.iter().collect();
-                let current_values: HashSet<_> = current_schema.enum_values.iter().collect();
 
-                let added_values: Vec<_> = current_values
-                    .difference(&base_values)
-                    .map(|v| (*v).clone())
-                    .collect();
-
-                if !added_values.is_empty() {
-                    vec![EnumValuesAddedRule {
-                        schema_name: schema_name.to_string(),
-                        property_path: property_path.to_string(),
-                        values: added_values,
-                    }]
-                } else {
-                    vec![]
-                }
-            }
-            _ => vec![],
+    /// Lowercase letters and digits only, so `IN_PROGRESS` matches `inProgress`
+    fn normalized(value: &serde_json::Value) -> String {
+        Self::text(value)
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+
+    /// Index of the added value `old` was most likely renamed to: the same value spelled
+    /// differently, otherwise a string value replaced in the same position of the list
+    fn likely_rename(
+        old: &serde_json::Value,
+        old_position: usize,
+        added: &[(usize, &serde_json::Value)],
+    ) -> Option<usize> {
+        if !old.is_string() {
+            return None;
         }
+        let strings = || {
+            added
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, new))| new.is_string())
+        };
+        strings()
+            .find(|(_, (_, new))| Self::normalized(old) == Self::normalized(new))
+            .or_else(|| strings().find(|(_, (new_position, _))| *new_position == old_position))
+            .map(|(index, _)| index)
     }
 }
 
-/// Enum values removed
-#[derive(Debug, Clone)]
-pub struct EnumValuesRemovedRule {
-    pub schema_name: String,
-    pub property_path: String,
-    pub values: Vec<serde_json::Value>,
-}
-
-impl Rule for EnumValuesRemovedRule {
+impl Rule for EnumChangedRule {
     fn name(&self) -> &str {
-        "EnumValuesRemoved"
+        "EnumChanged"
     }
 
     fn description(&self) -> String {
-        let values_str = self
-            .values
-            .iter()
-            .map(|v| v.to_string())
-            .collect::<Vec<_>>()
-            .join(", ");
-        format!("Enum values removed: [{}]", values_str)
+        let list = |values: &[serde_json::Value]| {
+            values
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let pairs = |pairs: &[(serde_json::Value, serde_json::Value)]| {
+            pairs
+                .iter()
+                .map(|(old, new)| format!("{} → {}", old, new))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let mut parts = Vec::new();
+        if !self.added.is_empty() {
+            parts.push(format!("added [{}]", list(&self.added)));
+        }
+        if !self.removed.is_empty() {
+            parts.push(format!("removed [{}]", list(&self.removed)));
+        }
+        if !self.renamed.is_empty() {
+            parts.push(format!("likely renamed {}", pairs(&self.renamed)));
+        }
+        if !self.type_changed.is_empty() {
+            parts.push(format!("type changed {}", pairs(&self.type_changed)));
+        }
+        format!("Enum values changed: {}", parts.join("; "))
     }
 
     fn change_level(&self) -> ChangeLevel {
-        ChangeLevel::Breaking
+        if self.removed.is_empty() && self.renamed.is_empty() && self.type_changed.is_empty() {
+            ChangeLevel::Change
+        } else {
+            ChangeLevel::Breaking
+        }
     }
 
     fn context(&self) -> crate::rules::ChangeAnchor {
@@ -515,37 +563,78 @@ impl Rule for EnumValuesRemovedRule {
             crate::rules::ChangeAnchor::EnumValues(self.property_path.clone())
         }
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(Some(&self.old_values), Some(&self.new_values))
+    }
 }
 
-impl SchemaRule for EnumValuesRemovedRule {
+impl SchemaRule for EnumChangedRule {
     fn detect(
         schema_name: &str,
         property_path: &str,
         base: Option<&ObjectSchema>,
         current: Option<&ObjectSchema>,
     ) -> Vec<Self> {
-        match (base, current) {
-            (Some(base_schema), Some(current_schema)) => {
-                let base_values: HashSet<_> = base_schema.enum_values.iter().collect();
-                let current_values: HashSet<_> = current_schema.enum_values.iter().collect();
+        let (Some(base_schema), Some(current_schema)) = (base, current) else {
+            return vec![];
+        };
 
-                let removed_values: Vec<_> = base_values
-                    .difference(&current_values)
-                    .map(|v| (*v).clone())
-                    .collect();
+        let old_values = &base_schema.enum_values;
+        let new_values = &current_schema.enum_values;
+        let mut removed: Vec<(usize, &serde_json::Value)> = old_values
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| !new_values.contains(value))
+            .collect();
+        let mut added: Vec<(usize, &serde_json::Value)> = new_values
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| !old_values.contains(value))
+            .collect();
 
-                if !removed_values.is_empty() {
-                    vec![EnumValuesRemovedRule {
-                        schema_name: schema_name.to_string(),
-                        property_path: property_path.to_string(),
-                        values: removed_values,
-                    }]
-                } else {
-                    vec![]
+        if removed.is_empty() && added.is_empty() {
+            return vec![];
+        }
+
+        // Pair up values written with another JSON type first, then likely renames
+        let mut type_changed = Vec::new();
+        let mut renamed = Vec::new();
+        removed.retain(|(old_position, old)| {
+            let pair = added
+                .iter()
+                .position(|(_, new)| Self::text(old) == Self::text(new))
+                .or_else(|| Self::likely_rename(old, *old_position, &added));
+
+            match pair {
+                Some(index) => {
+                    let (_, new) = added.remove(index);
+                    if Self::text(old) == Self::text(new) {
+                        type_changed.push(((*old).clone(), new.clone()));
+                    } else {
+                        renamed.push(((*old).clone(), new.clone()));
+                    }
+                    false
                 }
+                None => true,
             }
-            _ => vec![],
-        }
+        });
+
+        vec![EnumChangedRule {
+            schema_name: schema_name.to_string(),
+            property_path: property_path.to_string(),
+            old_values: old_values.clone(),
+            new_values: new_values.clone(),
+            added: added.into_iter().map(|(_, value)| value.clone()).collect(),
+            removed: removed
+                .into_iter()
+                .map(|(_, value)| value.clone())
+                .collect(),
+            renamed,
+            type_changed,
+        }]
     }
 }
 
@@ -582,6 +671,12 @@ impl Rule for FormatChangedRule {
             crate::rules::ChangeAnchor::Format(self.property_path.clone())
         }
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(self.old_format.as_ref(), self.new_format.as_ref())
+    }
 }
 
 impl SchemaRule for FormatChangedRule {
@@ -645,6 +740,12 @@ impl Rule for NullableChangedRule {
             crate::rules::ChangeAnchor::Nullable(self.property_path.clone())
         }
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(Some(self.old_nullable), Some(self.new_nullable))
+    }
 }
 
 impl SchemaRule for NullableChangedRule {
@@ -656,8 +757,8 @@ impl SchemaRule for NullableChangedRule {
     ) -> Vec<Self> {
         match (base, current) {
             (Some(base_schema), Some(current_schema)) => {
-                let base_nullable = base_schema.is_nullable().unwrap_or(false);
-                let current_nullable = current_schema.is_nullable().unwrap_or(false);
+                let base_nullable = is_nullable(base_schema);
+                let current_nullable = is_nullable(current_schema);
 
                 if base_nullable != current_nullable {
                     vec![NullableChangedRule {
@@ -704,6 +805,10 @@ impl Rule for ArrayItemsChangedRule {
             crate::rules::ChangeAnchor::ArrayItems(self.property_path.clone())
         }
     }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self).in_schema(&self.schema_name)
+    }
 }
 
 impl SchemaRule for ArrayItemsChangedRule {
@@ -720,140 +825,1379 @@ impl SchemaRule for ArrayItemsChangedRule {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use oas3::spec::{SchemaType, SchemaTypeSet};
-    use std::collections::BTreeMap;
+/// Check if a schema accepts null, either 3.0 style (`nullable: true`), 3.1 style (type array),
+/// or as `anyOf`/`oneOf` of a `{type: null}` branch and one other branch
+pub fn is_nullable(schema: &ObjectSchema) -> bool {
+    let is_null = |branch: &ObjectOrReference<ObjectSchema>| {
+        matches!(branch, ObjectOrReference::Object(branch)
+            if branch.schema_type == Some(SchemaTypeSet::Single(SchemaType::Null)))
+    };
+    let has_null_branch = |branches: &[ObjectOrReference<ObjectSchema>]| {
+        branches.len() == 2 && branches.iter().filter(|branch| is_null(branch)).count() == 1
+    };
+
+    schema.is_nullable().unwrap_or(false)
+        || carried_keyword(schema, "nullable") == Some(&serde_json::Value::Bool(true))
+        || has_null_branch(&schema.any_of)
+        || has_null_branch(&schema.one_of)
+}
 
-    fn create_test_schema(schema_type: Option<SchemaTypeSet>) -> ObjectSchema {
-        ObjectSchema {
-            schema_type: schema_type,
-            properties: BTreeMap::new(),
-            required: vec![],
-            description: None,
-            enum_values: vec![],
-            format: None,
-            ..Default::default()
-        }
+/// Schema types other than "null", sorted so that type arrays compare by content
+fn non_null_types(schema: &ObjectSchema) -> Option<Vec<String>> {
+    let types = match schema.schema_type.as_ref()? {
+        SchemaTypeSet::Single(schema_type) => vec![*schema_type],
+        SchemaTypeSet::Multiple(types) => types.clone(),
+    };
+
+    let mut types: Vec<String> = types
+        .into_iter()
+        .filter(|schema_type| *schema_type != SchemaType::Null)
+        .map(|schema_type| format!("{:?}", schema_type))
+        .collect();
+    types.sort();
+    types.dedup();
+    Some(types)
+}
+
+/// JSON Schema keyword the loader moved into an extension, since the OpenAPI model drops it
+pub fn carried_keyword<'a>(
+    schema: &'a ObjectSchema,
+    keyword: &str,
+) -> Option<&'a serde_json::Value> {
+    // Extension keys are stored without the "x-" prefix
+    let prefix = crate::loader::KEYWORD_EXTENSION_PREFIX.trim_start_matches("x-");
+    schema.extensions.get(&format!("{}{}", prefix, keyword))
+}
+
+/// Path of a property inside the object at `property_path`
+fn join_path(property_path: &str, property_name: &str) -> String {
+    if property_path.is_empty() {
+        property_name.to_string()
+    } else {
+        format!("{}.{}", property_path, property_name)
     }
+}
 
-    fn create_nullable_schema(nullable: bool) -> ObjectSchema {
-        let schema = if nullable {
-            create_test_schema(Some(SchemaTypeSet::Single(SchemaType::Null)))
-        } else {
-            create_test_schema(Some(SchemaTypeSet::Single(SchemaType::Object)))
-        };
-        schema
+fn property_anchor(property_path: &str) -> crate::rules::ChangeAnchor {
+    if property_path.is_empty() {
+        crate::rules::ChangeAnchor::Schema
+    } else {
+        crate::rules::ChangeAnchor::Property(property_path.to_string())
     }
+}
 
-    #[test]
-    fn test_schema_added_rule_detection() {
-        // Test detection when schema is added (base is None, current is Some)
-        let current = create_test_schema(None);
-        let detected = SchemaAddedRule::detect("User", "", None, Some(&current));
+/// Const value added, removed or changed
+#[derive(Debug, Clone)]
+pub struct ConstChangedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    pub old_value: Option<serde_json::Value>,
+    pub new_value: Option<serde_json::Value>,
+}
 
-        assert_eq!(detected.len(), 1);
-        assert_eq!(detected[0].name(), "SchemaAdded");
-        assert_eq!(detected[0].change_level(), ChangeLevel::Change);
-        assert!(detected[0].description().contains("User"));
+impl Rule for ConstChangedRule {
+    fn name(&self) -> &str {
+        "ConstChanged"
     }
 
-    #[test]
-    fn test_schema_added_rule_no_detection() {
-        // Should not detect when both schemas exist
-        let base = create_test_schema(None);
-        let current = create_test_schema(None);
-        let detected = SchemaAddedRule::detect("User", "", Some(&base), Some(&current));
-
-        assert_eq!(detected.len(), 0);
+    fn description(&self) -> String {
+        match (&self.old_value, &self.new_value) {
+            (None, Some(new)) => format!("Const value {} added", new),
+            (Some(old), None) => format!("Const value {} removed", old),
+            (Some(old), Some(new)) => format!("Const value changed from {} to {}", old, new),
+            (None, None) => "Const value changed".to_string(),
+        }
     }
 
-    #[test]
-    fn test_schema_removed_rule_detection() {
-        // Test detection when schema is removed (base is Some, current is None)
-        let base = create_test_schema(None);
-        let detected = SchemaRemovedRule::detect("User", "", Some(&base), None);
+    fn change_level(&self) -> ChangeLevel {
+        match (&self.old_value, &self.new_value) {
+            // Dropping a const only allows more values
+            (Some(_), None) => ChangeLevel::Change,
+            _ => ChangeLevel::Breaking,
+        }
+    }
 
-        assert_eq!(detected.len(), 1);
-        assert_eq!(detected[0].name(), "SchemaRemoved");
-        assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
-        assert!(detected[0].description().contains("User"));
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        property_anchor(&self.property_path)
     }
 
-    #[test]
-    fn test_schema_removed_rule_no_detection() {
-        // Should not detect when both schemas exist
-        let base = create_test_schema(None);
-        let current = create_test_schema(None);
-        let detected = SchemaRemovedRule::detect("User", "", Some(&base), Some(&current));
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(self.old_value.as_ref(), self.new_value.as_ref())
+    }
+}
 
-        assert_eq!(detected.len(), 0);
+impl SchemaRule for ConstChangedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            (Some(base_schema), Some(current_schema))
+                if base_schema.const_value != current_schema.const_value =>
+            {
+                vec![ConstChangedRule {
+                    schema_name: schema_name.to_string(),
+                    property_path: property_path.to_string(),
+                    old_value: base_schema.const_value.clone(),
+                    new_value: current_schema.const_value.clone(),
+                }]
+            }
+            _ => vec![],
+        }
     }
+}
 
-    #[test]
-    fn test_type_changed_rule_detection() {
-        let base = create_test_schema(Some(SchemaTypeSet::Single(SchemaType::String)));
-        let current = create_test_schema(Some(SchemaTypeSet::Single(SchemaType::Number)));
-        let detected = TypeChangedRule::detect("User", "email", Some(&base), Some(&current));
+/// Tuple positions (`prefixItems`) added, removed or changed
+#[derive(Debug, Clone)]
+pub struct PrefixItemsChangedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    pub old_length: usize,
+    pub new_length: usize,
+    /// Positions present in both versions with a different schema
+    pub changed_positions: Vec<usize>,
+}
 
-        assert_eq!(detected.len(), 1);
-        assert_eq!(detected[0].name(), "TypeChanged");
-        assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
-        assert!(detected[0].description().contains("String"));
-        assert!(detected[0].description().contains("Number"));
+impl Rule for PrefixItemsChangedRule {
+    fn name(&self) -> &str {
+        "PrefixItemsChanged"
     }
 
-    #[test]
-    fn test_type_changed_rule_no_detection() {
-        let base = create_test_schema(Some(SchemaTypeSet::Single(SchemaType::String)));
-        let current = create_test_schema(Some(SchemaTypeSet::Single(SchemaType::String)));
-        let detected = TypeChangedRule::detect("User", "", Some(&base), Some(&current));
+    fn description(&self) -> String {
+        let mut parts = Vec::new();
+        if self.old_length != self.new_length {
+            parts.push(format!(
+                "tuple length changed from {} to {}",
+                self.old_length, self.new_length
+            ));
+        }
+        if !self.changed_positions.is_empty() {
+            let positions: Vec<String> = self
+                .changed_positions
+                .iter()
+                .map(|p| p.to_string())
+                .collect();
+            parts.push(format!("positions {} changed", positions.join(", ")));
+        }
+        format!("Prefix items changed: {}", parts.join(", "))
+    }
 
-        assert_eq!(detected.len(), 0);
+    fn change_level(&self) -> ChangeLevel {
+        if self.changed_positions.is_empty() && self.new_length > self.old_length {
+            ChangeLevel::Warning
+        } else {
+            ChangeLevel::Breaking
+        }
     }
 
-    #[test]
-    fn test_property_added_rule_detection() {
-        let base = create_test_schema(None);
-        let mut current = create_test_schema(None);
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        if self.property_path.is_empty() {
+            crate::rules::ChangeAnchor::Schema
+        } else {
+            crate::rules::ChangeAnchor::ArrayItems(self.property_path.clone())
+        }
+    }
 
-        // Add a property to current that doesn't exist in base
-        current.properties.insert(
-            "email".to_string(),
-            oas3::spec::ObjectOrReference::Object(create_test_schema(Some(SchemaTypeSet::Single(
-                SchemaType::String,
-            )))),
-        );
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(Some(self.old_length), Some(self.new_length))
+    }
+}
 
-        let detected = PropertyAddedRule::detect("User", "", Some(&base), Some(&current));
+impl SchemaRule for PrefixItemsChangedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            (Some(base_schema), Some(current_schema))
+                if base_schema.prefix_items != current_schema.prefix_items =>
+            {
+                let changed_positions = base_schema
+                    .prefix_items
+                    .iter()
+                    .zip(&current_schema.prefix_items)
+                    .enumerate()
+                    .filter(|(_, (base_item, current_item))| base_item != current_item)
+                    .map(|(position, _)| position)
+                    .collect();
 
-        assert_eq!(detected.len(), 1);
-        assert_eq!(detected[0].name(), "PropertyAdded");
-        assert_eq!(detected[0].property_name, "email");
-        assert_eq!(detected[0].change_level(), ChangeLevel::Change);
+                vec![PrefixItemsChangedRule {
+                    schema_name: schema_name.to_string(),
+                    property_path: property_path.to_string(),
+                    old_length: base_schema.prefix_items.len(),
+                    new_length: current_schema.prefix_items.len(),
+                    changed_positions,
+                }]
+            }
+            _ => vec![],
+        }
     }
+}
 
-    #[test]
-    fn test_property_removed_rule_detection_optional() {
-        let mut base = create_test_schema(None);
-        let current = create_test_schema(None);
+/// Conditional subschemas (`if`/`then`/`else`, `dependentSchemas`) changed
+#[derive(Debug, Clone)]
+pub struct ConditionalSchemaChangedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    pub keywords: Vec<String>,
+}
 
-        // Add a property to base that doesn't exist in current (and it's not required)
-        base.properties.insert(
-            "email".to_string(),
-            oas3::spec::ObjectOrReference::Object(create_test_schema(Some(SchemaTypeSet::Single(
-                SchemaType::String,
-            )))),
-        );
+impl ConditionalSchemaChangedRule {
+    const KEYWORDS: [&'static str; 4] = ["if", "then", "else", "dependentSchemas"];
+}
+
+impl Rule for ConditionalSchemaChangedRule {
+    fn name(&self) -> &str {
+        "ConditionalSchemaChanged"
+    }
+
+    fn description(&self) -> String {
+        format!("Conditional schema changed: {}", self.keywords.join(", "))
+    }
+
+    fn change_level(&self) -> ChangeLevel {
+        // Effect depends on the conditions, so it needs a closer look
+        ChangeLevel::Warning
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        property_anchor(&self.property_path)
+    }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self).in_schema(&self.schema_name)
+    }
+}
+
+impl SchemaRule for ConditionalSchemaChangedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            (Some(base_schema), Some(current_schema)) => {
+                let keywords: Vec<String> = Self::KEYWORDS
+                    .iter()
+                    .filter(|keyword| {
+                        carried_keyword(base_schema, keyword)
+                            != carried_keyword(current_schema, keyword)
+                    })
+                    .map(|keyword| keyword.to_string())
+                    .collect();
+
+                if keywords.is_empty() {
+                    vec![]
+                } else {
+                    vec![ConditionalSchemaChangedRule {
+                        schema_name: schema_name.to_string(),
+                        property_path: property_path.to_string(),
+                        keywords,
+                    }]
+                }
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// Properties required only when another property is present (`dependentRequired`) changed
+#[derive(Debug, Clone)]
+pub struct DependentRequiredChangedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    /// Added dependencies as "property -> dependent"
+    pub added: Vec<String>,
+    /// Removed dependencies as "property -> dependent"
+    pub removed: Vec<String>,
+}
+
+impl Rule for DependentRequiredChangedRule {
+    fn name(&self) -> &str {
+        "DependentRequiredChanged"
+    }
+
+    fn description(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.added.is_empty() {
+            parts.push(format!("added {}", self.added.join(", ")));
+        }
+        if !self.removed.is_empty() {
+            parts.push(format!("removed {}", self.removed.join(", ")));
+        }
+        format!("Dependent required properties {}", parts.join("; "))
+    }
+
+    fn change_level(&self) -> ChangeLevel {
+        if self.added.is_empty() {
+            ChangeLevel::Change
+        } else {
+            ChangeLevel::Breaking
+        }
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        crate::rules::ChangeAnchor::Required
+    }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(Some(&self.removed), Some(&self.added))
+    }
+}
+
+impl DependentRequiredChangedRule {
+    fn dependencies(schema: &ObjectSchema) -> HashSet<String> {
+        let Some(serde_json::Value::Object(dependencies)) =
+            carried_keyword(schema, "dependentRequired")
+        else {
+            return HashSet::new();
+        };
+
+        dependencies
+            .iter()
+            .flat_map(|(property, dependents)| {
+                dependents
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|dependent| dependent.as_str())
+                    .map(move |dependent| format!("{} -> {}", property, dependent))
+            })
+            .collect()
+    }
+}
+
+impl SchemaRule for DependentRequiredChangedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            (Some(base_schema), Some(current_schema)) => {
+                let base_dependencies = Self::dependencies(base_schema);
+                let current_dependencies = Self::dependencies(current_schema);
+
+                let mut added: Vec<String> = current_dependencies
+                    .difference(&base_dependencies)
+                    .cloned()
+                    .collect();
+                let mut removed: Vec<String> = base_dependencies
+                    .difference(&current_dependencies)
+                    .cloned()
+                    .collect();
+
+                if added.is_empty() && removed.is_empty() {
+                    return vec![];
+                }
+
+                added.sort();
+                removed.sort();
+                vec![DependentRequiredChangedRule {
+                    schema_name: schema_name.to_string(),
+                    property_path: property_path.to_string(),
+                    added,
+                    removed,
+                }]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// `unevaluatedProperties` changed, e.g. an object was closed for unknown properties
+#[derive(Debug, Clone)]
+pub struct UnevaluatedPropertiesChangedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    pub old_value: Option<serde_json::Value>,
+    pub new_value: Option<serde_json::Value>,
+}
+
+impl Rule for UnevaluatedPropertiesChangedRule {
+    fn name(&self) -> &str {
+        "UnevaluatedPropertiesChanged"
+    }
+
+    fn description(&self) -> String {
+        let show = |value: &Option<serde_json::Value>| match value {
+            Some(value) => value.to_string(),
+            None => "unset".to_string(),
+        };
+        format!(
+            "Unevaluated properties changed from {} to {}",
+            show(&self.old_value),
+            show(&self.new_value)
+        )
+    }
+
+    fn change_level(&self) -> ChangeLevel {
+        let closed =
+            |value: &Option<serde_json::Value>| *value == Some(serde_json::Value::Bool(false));
+        match (closed(&self.old_value), closed(&self.new_value)) {
+            (false, true) => ChangeLevel::Breaking,
+            (true, false) => ChangeLevel::Change,
+            _ => ChangeLevel::Warning,
+        }
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        property_anchor(&self.property_path)
+    }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(self.old_value.as_ref(), self.new_value.as_ref())
+    }
+}
+
+impl SchemaRule for UnevaluatedPropertiesChangedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            (Some(base_schema), Some(current_schema)) => {
+                let old_value = carried_keyword(base_schema, "unevaluatedProperties");
+                let new_value = carried_keyword(current_schema, "unevaluatedProperties");
+
+                if old_value == new_value {
+                    vec![]
+                } else {
+                    vec![UnevaluatedPropertiesChangedRule {
+                        schema_name: schema_name.to_string(),
+                        property_path: property_path.to_string(),
+                        old_value: old_value.cloned(),
+                        new_value: new_value.cloned(),
+                    }]
+                }
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// Local definitions (`$defs`) added, removed or changed
+#[derive(Debug, Clone)]
+pub struct DefsChangedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl Rule for DefsChangedRule {
+    fn name(&self) -> &str {
+        "DefsChanged"
+    }
+
+    fn description(&self) -> String {
+        let mut parts = Vec::new();
+        for (label, names) in [
+            ("added", &self.added),
+            ("removed", &self.removed),
+            ("changed", &self.changed),
+        ] {
+            if !names.is_empty() {
+                parts.push(format!("{} {}", label, names.join(", ")));
+            }
+        }
+        format!("Definitions ($defs) {}", parts.join("; "))
+    }
+
+    fn change_level(&self) -> ChangeLevel {
+        if self.removed.is_empty() && self.changed.is_empty() {
+            ChangeLevel::Change
+        } else {
+            ChangeLevel::Warning
+        }
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        property_anchor(&self.property_path)
+    }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self).in_schema(&self.schema_name)
+    }
+}
+
+impl SchemaRule for DefsChangedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        let (Some(base_schema), Some(current_schema)) = (base, current) else {
+            return vec![];
+        };
+
+        let empty = serde_json::Map::new();
+        let defs = |schema| match carried_keyword(schema, "$defs") {
+            Some(serde_json::Value::Object(defs)) => defs,
+            _ => &empty,
+        };
+        let base_defs = defs(base_schema);
+        let current_defs = defs(current_schema);
+
+        let added: Vec<String> = current_defs
+            .keys()
+            .filter(|name| !base_defs.contains_key(*name))
+            .cloned()
+            .collect();
+        let removed: Vec<String> = base_defs
+            .keys()
+            .filter(|name| !current_defs.contains_key(*name))
+            .cloned()
+            .collect();
+        let changed: Vec<String> = base_defs
+            .iter()
+            .filter(
+                |(name, def)| matches!(current_defs.get(*name), Some(current) if current != *def),
+            )
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        if added.is_empty() && removed.is_empty() && changed.is_empty() {
+            return vec![];
+        }
+
+        vec![DefsChangedRule {
+            schema_name: schema_name.to_string(),
+            property_path: property_path.to_string(),
+            added,
+            removed,
+            changed,
+        }]
+    }
+}
+
+/// What an object accepts besides its declared properties
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdditionalProperties {
+    /// `additionalProperties` is not set, which allows anything
+    Unset,
+    /// `additionalProperties: true`
+    Allowed,
+    /// `additionalProperties: false`
+    Forbidden,
+    /// `additionalProperties` is a schema, named when it is a `$ref`
+    Schema(Option<String>),
+}
+
+impl AdditionalProperties {
+    pub fn of(schema: &ObjectSchema) -> Self {
+        match &schema.additional_properties {
+            None => AdditionalProperties::Unset,
+            Some(Schema::Boolean(BooleanSchema(true))) => AdditionalProperties::Allowed,
+            Some(Schema::Boolean(BooleanSchema(false))) => AdditionalProperties::Forbidden,
+            Some(Schema::Object(value_schema)) => match value_schema.as_ref() {
+                ObjectOrReference::Ref { ref_path, .. } => AdditionalProperties::Schema(Some(
+                    ref_path.rsplit('/').next().unwrap_or(ref_path).to_string(),
+                )),
+                ObjectOrReference::Object(_) => AdditionalProperties::Schema(None),
+            },
+        }
+    }
+
+    /// Whether any additional property is accepted
+    fn is_open(&self) -> bool {
+        matches!(
+            self,
+            AdditionalProperties::Unset | AdditionalProperties::Allowed
+        )
+    }
+}
+
+impl std::fmt::Display for AdditionalProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdditionalProperties::Unset => write!(f, "unset"),
+            AdditionalProperties::Allowed => write!(f, "true"),
+            AdditionalProperties::Forbidden => write!(f, "false"),
+            AdditionalProperties::Schema(Some(name)) => write!(f, "schema {}", name),
+            AdditionalProperties::Schema(None) => write!(f, "inline schema"),
+        }
+    }
+}
+
+/// Object no longer accepts additional properties (`additionalProperties: false`)
+#[derive(Debug, Clone)]
+pub struct AdditionalPropertiesClosedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    pub old_value: AdditionalProperties,
+}
+
+impl Rule for AdditionalPropertiesClosedRule {
+    fn name(&self) -> &str {
+        "AdditionalPropertiesClosed"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Additional properties are no longer allowed (was {})",
+            self.old_value
+        )
+    }
+
+    fn change_level(&self) -> ChangeLevel {
+        // Senders of unknown properties get rejected
+        ChangeLevel::Breaking
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        property_anchor(&self.property_path)
+    }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(
+                Some(self.old_value.to_string()),
+                Some(AdditionalProperties::Forbidden.to_string()),
+            )
+    }
+}
+
+impl SchemaRule for AdditionalPropertiesClosedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            (Some(base_schema), Some(current_schema)) => {
+                let old_value = AdditionalProperties::of(base_schema);
+                let new_value = AdditionalProperties::of(current_schema);
+
+                if old_value != AdditionalProperties::Forbidden
+                    && new_value == AdditionalProperties::Forbidden
+                {
+                    vec![AdditionalPropertiesClosedRule {
+                        schema_name: schema_name.to_string(),
+                        property_path: property_path.to_string(),
+                        old_value,
+                    }]
+                } else {
+                    vec![]
+                }
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// `additionalProperties` switched between allowed, forbidden and a value schema
+#[derive(Debug, Clone)]
+pub struct AdditionalPropertiesChangedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    pub old_value: AdditionalProperties,
+    pub new_value: AdditionalProperties,
+}
+
+impl Rule for AdditionalPropertiesChangedRule {
+    fn name(&self) -> &str {
+        "AdditionalPropertiesChanged"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Additional properties changed from {} to {}",
+            self.old_value, self.new_value
+        )
+    }
+
+    fn change_level(&self) -> ChangeLevel {
+        if self.new_value.is_open() {
+            // Accepting more is compatible
+            ChangeLevel::Change
+        } else {
+            // Values are now restricted by a (different) schema
+            ChangeLevel::Warning
+        }
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        property_anchor(&self.property_path)
+    }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(
+                Some(self.old_value.to_string()),
+                Some(self.new_value.to_string()),
+            )
+    }
+}
+
+impl SchemaRule for AdditionalPropertiesChangedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            (Some(base_schema), Some(current_schema)) => {
+                let old_value = AdditionalProperties::of(base_schema);
+                let new_value = AdditionalProperties::of(current_schema);
+
+                // Closing is reported by AdditionalPropertiesClosedRule, unset and true are equal
+                // and changes inside inline value schemas are found by recursion
+                let unchanged =
+                    old_value == new_value || (old_value.is_open() && new_value.is_open());
+                if unchanged || new_value == AdditionalProperties::Forbidden {
+                    vec![]
+                } else {
+                    vec![AdditionalPropertiesChangedRule {
+                        schema_name: schema_name.to_string(),
+                        property_path: property_path.to_string(),
+                        old_value,
+                        new_value,
+                    }]
+                }
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// `readOnly` flag changed
+#[derive(Debug, Clone)]
+pub struct ReadOnlyChangedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    pub old_read_only: bool,
+    pub new_read_only: bool,
+}
+
+impl Rule for ReadOnlyChangedRule {
+    fn name(&self) -> &str {
+        "ReadOnlyChanged"
+    }
+
+    fn description(&self) -> String {
+        if self.new_read_only {
+            "Became read-only, values sent in requests are ignored".to_string()
+        } else {
+            "Is no longer read-only".to_string()
+        }
+    }
+
+    fn change_level(&self) -> ChangeLevel {
+        if self.new_read_only {
+            ChangeLevel::Warning
+        } else {
+            ChangeLevel::Change
+        }
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        property_anchor(&self.property_path)
+    }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(Some(self.old_read_only), Some(self.new_read_only))
+    }
+}
+
+impl SchemaRule for ReadOnlyChangedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            (Some(base_schema), Some(current_schema)) => {
+                let old_read_only = base_schema.read_only.unwrap_or(false);
+                let new_read_only = current_schema.read_only.unwrap_or(false);
+
+                if old_read_only != new_read_only {
+                    vec![ReadOnlyChangedRule {
+                        schema_name: schema_name.to_string(),
+                        property_path: property_path.to_string(),
+                        old_read_only,
+                        new_read_only,
+                    }]
+                } else {
+                    vec![]
+                }
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// `writeOnly` flag changed
+#[derive(Debug, Clone)]
+pub struct WriteOnlyChangedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    pub old_write_only: bool,
+    pub new_write_only: bool,
+}
+
+impl Rule for WriteOnlyChangedRule {
+    fn name(&self) -> &str {
+        "WriteOnlyChanged"
+    }
+
+    fn description(&self) -> String {
+        if self.new_write_only {
+            "Became write-only, no longer returned in responses".to_string()
+        } else {
+            "Is no longer write-only".to_string()
+        }
+    }
+
+    fn change_level(&self) -> ChangeLevel {
+        if self.new_write_only {
+            ChangeLevel::Breaking
+        } else {
+            ChangeLevel::Change
+        }
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        property_anchor(&self.property_path)
+    }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(Some(self.old_write_only), Some(self.new_write_only))
+    }
+}
+
+impl SchemaRule for WriteOnlyChangedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            (Some(base_schema), Some(current_schema)) => {
+                let old_write_only = base_schema.write_only.unwrap_or(false);
+                let new_write_only = current_schema.write_only.unwrap_or(false);
+
+                if old_write_only != new_write_only {
+                    vec![WriteOnlyChangedRule {
+                        schema_name: schema_name.to_string(),
+                        property_path: property_path.to_string(),
+                        old_write_only,
+                        new_write_only,
+                    }]
+                } else {
+                    vec![]
+                }
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// Default value added, removed or changed
+#[derive(Debug, Clone)]
+pub struct DefaultChangedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    pub old_default: Option<serde_json::Value>,
+    pub new_default: Option<serde_json::Value>,
+}
+
+impl Rule for DefaultChangedRule {
+    fn name(&self) -> &str {
+        "DefaultChanged"
+    }
+
+    fn description(&self) -> String {
+        match (&self.old_default, &self.new_default) {
+            (None, Some(new)) => format!("Default value {} added", new),
+            (Some(old), None) => format!("Default value {} removed", old),
+            (Some(old), Some(new)) => format!("Default value changed from {} to {}", old, new),
+            (None, None) => "Default value changed".to_string(),
+        }
+    }
+
+    fn change_level(&self) -> ChangeLevel {
+        // Clients omitting the field silently get a different behaviour
+        ChangeLevel::Warning
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        property_anchor(&self.property_path)
+    }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(self.old_default.as_ref(), self.new_default.as_ref())
+    }
+}
+
+impl SchemaRule for DefaultChangedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            (Some(base_schema), Some(current_schema))
+                if base_schema.default != current_schema.default =>
+            {
+                vec![DefaultChangedRule {
+                    schema_name: schema_name.to_string(),
+                    property_path: property_path.to_string(),
+                    old_default: base_schema.default.clone(),
+                    new_default: current_schema.default.clone(),
+                }]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// Documentation examples (`example`/`examples`) changed
+#[derive(Debug, Clone)]
+pub struct ExampleChangedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    pub old_examples: Vec<serde_json::Value>,
+    pub new_examples: Vec<serde_json::Value>,
+}
+
+impl ExampleChangedRule {
+    fn examples(schema: &ObjectSchema) -> Vec<serde_json::Value> {
+        schema
+            .example
+            .iter()
+            .chain(&schema.examples)
+            .cloned()
+            .collect()
+    }
+}
+
+impl Rule for ExampleChangedRule {
+    fn name(&self) -> &str {
+        "ExampleChanged"
+    }
+
+    fn description(&self) -> String {
+        let show = |examples: &[serde_json::Value]| {
+            if examples.is_empty() {
+                "none".to_string()
+            } else {
+                examples
+                    .iter()
+                    .map(|example| example.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+        };
+        format!(
+            "Example changed from {} to {}",
+            show(&self.old_examples),
+            show(&self.new_examples)
+        )
+    }
+
+    fn change_level(&self) -> ChangeLevel {
+        // Documentation only
+        ChangeLevel::Change
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        property_anchor(&self.property_path)
+    }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(Some(&self.old_examples), Some(&self.new_examples))
+    }
+}
+
+impl SchemaRule for ExampleChangedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            (Some(base_schema), Some(current_schema)) => {
+                let old_examples = Self::examples(base_schema);
+                let new_examples = Self::examples(current_schema);
+
+                if old_examples != new_examples {
+                    vec![ExampleChangedRule {
+                        schema_name: schema_name.to_string(),
+                        property_path: property_path.to_string(),
+                        old_examples,
+                        new_examples,
+                    }]
+                } else {
+                    vec![]
+                }
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// Discriminator values of a polymorphic schema with the names of their subtype schemas.
+///
+/// Explicit `mapping` entries override the implicit ones, which use the names of `oneOf`/`anyOf` refs.
+pub fn discriminator_mapping(schema: &ObjectSchema) -> BTreeMap<String, String> {
+    let Some(discriminator) = &schema.discriminator else {
+        return BTreeMap::new();
+    };
+
+    let schema_name = |target: &str| target.rsplit('/').next().unwrap_or(target).to_string();
+    let mut mapping: BTreeMap<String, String> = schema
+        .one_of
+        .iter()
+        .chain(&schema.any_of)
+        .filter_map(|subtype| match subtype {
+            ObjectOrReference::Ref { ref_path, .. } => {
+                let name = schema_name(ref_path);
+                Some((name.clone(), name))
+            }
+            ObjectOrReference::Object(_) => None,
+        })
+        .collect();
+
+    for (value, target) in discriminator.mapping.iter().flatten() {
+        // Explicit mappings replace the implicit entry of the same schema
+        let target = schema_name(target);
+        mapping.retain(|_, implicit_target| *implicit_target != target);
+        mapping.insert(value.clone(), target);
+    }
+    mapping
+}
+
+/// Discriminator property added, removed or renamed
+#[derive(Debug, Clone)]
+pub struct DiscriminatorPropertyChangedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    pub old_property: Option<String>,
+    pub new_property: Option<String>,
+}
+
+impl Rule for DiscriminatorPropertyChangedRule {
+    fn name(&self) -> &str {
+        "DiscriminatorPropertyChanged"
+    }
+
+    fn description(&self) -> String {
+        match (&self.old_property, &self.new_property) {
+            (None, Some(new)) => format!("Discriminator on property '{}' added", new),
+            (Some(old), None) => format!("Discriminator on property '{}' removed", old),
+            (Some(old), Some(new)) => {
+                format!("Discriminator property renamed from '{}' to '{}'", old, new)
+            }
+            (None, None) => "Discriminator changed".to_string(),
+        }
+    }
+
+    fn change_level(&self) -> ChangeLevel {
+        if self.old_property.is_some() && self.new_property.is_some() {
+            // Payloads carry the type under another name
+            ChangeLevel::Breaking
+        } else {
+            ChangeLevel::Warning
+        }
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        property_anchor(&self.property_path)
+    }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(self.old_property.as_ref(), self.new_property.as_ref())
+    }
+}
+
+impl SchemaRule for DiscriminatorPropertyChangedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            (Some(base_schema), Some(current_schema)) => {
+                let property_name = |schema: &ObjectSchema| {
+                    schema
+                        .discriminator
+                        .as_ref()
+                        .map(|discriminator| discriminator.property_name.clone())
+                };
+                let old_property = property_name(base_schema);
+                let new_property = property_name(current_schema);
+
+                if old_property != new_property {
+                    vec![DiscriminatorPropertyChangedRule {
+                        schema_name: schema_name.to_string(),
+                        property_path: property_path.to_string(),
+                        old_property,
+                        new_property,
+                    }]
+                } else {
+                    vec![]
+                }
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// Discriminator value added, removed or mapped to another schema
+#[derive(Debug, Clone)]
+pub struct DiscriminatorMappingChangedRule {
+    pub schema_name: String,
+    pub property_path: String,
+    pub value: String,
+    pub old_target: Option<String>,
+    pub new_target: Option<String>,
+}
+
+impl Rule for DiscriminatorMappingChangedRule {
+    fn name(&self) -> &str {
+        "DiscriminatorMappingChanged"
+    }
+
+    fn description(&self) -> String {
+        match (&self.old_target, &self.new_target) {
+            (None, Some(new)) => format!("Discriminator value '{}' added ({})", self.value, new),
+            (Some(old), None) => {
+                format!("Discriminator value '{}' removed ({})", self.value, old)
+            }
+            (Some(old), Some(new)) => format!(
+                "Discriminator value '{}' now maps to {} instead of {}",
+                self.value, new, old
+            ),
+            (None, None) => format!("Discriminator value '{}' changed", self.value),
+        }
+    }
+
+    fn change_level(&self) -> ChangeLevel {
+        if self.old_target.is_none() {
+            ChangeLevel::Change
+        } else {
+            ChangeLevel::Breaking
+        }
+    }
+
+    fn context(&self) -> crate::rules::ChangeAnchor {
+        property_anchor(&self.property_path)
+    }
+
+    fn change(&self) -> Change {
+        Change::from_rule(self)
+            .in_schema(&self.schema_name)
+            .with_values(self.old_target.as_ref(), self.new_target.as_ref())
+    }
+}
+
+impl SchemaRule for DiscriminatorMappingChangedRule {
+    fn detect(
+        schema_name: &str,
+        property_path: &str,
+        base: Option<&ObjectSchema>,
+        current: Option<&ObjectSchema>,
+    ) -> Vec<Self> {
+        match (base, current) {
+            // Adding or removing the discriminator itself is reported by DiscriminatorPropertyChangedRule
+            (Some(base_schema), Some(current_schema))
+                if base_schema.discriminator.is_some()
+                    && current_schema.discriminator.is_some() =>
+            {
+                let base_mapping = discriminator_mapping(base_schema);
+                let current_mapping = discriminator_mapping(current_schema);
+
+                let values: std::collections::BTreeSet<&String> =
+                    base_mapping.keys().chain(current_mapping.keys()).collect();
+                values
+                    .into_iter()
+                    .filter(|value| base_mapping.get(*value) != current_mapping.get(*value))
+                    .map(|value| DiscriminatorMappingChangedRule {
+                        schema_name: schema_name.to_string(),
+                        property_path: property_path.to_string(),
+                        value: value.clone(),
+                        old_target: base_mapping.get(value).cloned(),
+                        new_target: current_mapping.get(value).cloned(),
+                    })
+                    .collect()
+            }
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn create_test_schema(schema_type: Option<SchemaTypeSet>) -> ObjectSchema {
+        ObjectSchema {
+            schema_type,
+            properties: BTreeMap::new(),
+            required: vec![],
+            description: None,
+            enum_values: vec![],
+            format: None,
+            ..Default::default()
+        }
+    }
+
+    fn create_nullable_schema(nullable: bool) -> ObjectSchema {
+        if nullable {
+            create_test_schema(Some(SchemaTypeSet::Single(SchemaType::Null)))
+        } else {
+            create_test_schema(Some(SchemaTypeSet::Single(SchemaType::Object)))
+        }
+    }
+
+    #[test]
+    fn test_schema_added_rule_detection() {
+        // Test detection when schema is added (base is None, current is Some)
+        let current = create_test_schema(None);
+        let detected = SchemaAddedRule::detect("User", "", None, Some(&current));
+
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].name(), "SchemaAdded");
+        assert_eq!(detected[0].change_level(), ChangeLevel::Change);
+        assert!(detected[0].description().contains("User"));
+    }
+
+    #[test]
+    fn test_schema_added_rule_no_detection() {
+        // Should not detect when both schemas exist
+        let base = create_test_schema(None);
+        let current = create_test_schema(None);
+        let detected = SchemaAddedRule::detect("User", "", Some(&base), Some(&current));
+
+        assert_eq!(detected.len(), 0);
+    }
+
+    #[test]
+    fn test_schema_removed_rule_detection() {
+        // Test detection when schema is removed (base is Some, current is None)
+        let base = create_test_schema(None);
+        let detected = SchemaRemovedRule::detect("User", "", Some(&base), None);
+
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].name(), "SchemaRemoved");
+        assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
+        assert!(detected[0].description().contains("User"));
+    }
+
+    #[test]
+    fn test_schema_removed_rule_no_detection() {
+        // Should not detect when both schemas exist
+        let base = create_test_schema(None);
+        let current = create_test_schema(None);
+        let detected = SchemaRemovedRule::detect("User", "", Some(&base), Some(&current));
+
+        assert_eq!(detected.len(), 0);
+    }
+
+    #[test]
+    fn test_type_changed_rule_detection() {
+        let base = create_test_schema(Some(SchemaTypeSet::Single(SchemaType::String)));
+        let current = create_test_schema(Some(SchemaTypeSet::Single(SchemaType::Number)));
+        let detected = TypeChangedRule::detect("User", "email", Some(&base), Some(&current));
+
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].name(), "TypeChanged");
+        assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
+        assert_eq!(
+            detected[0].description(),
+            "Type changed from 'string' to 'number'"
+        );
+    }
+
+    #[test]
+    fn test_type_changed_rule_no_detection() {
+        let base = create_test_schema(Some(SchemaTypeSet::Single(SchemaType::String)));
+        let current = create_test_schema(Some(SchemaTypeSet::Single(SchemaType::String)));
+        let detected = TypeChangedRule::detect("User", "", Some(&base), Some(&current));
+
+        assert_eq!(detected.len(), 0);
+    }
+
+    #[test]
+    fn test_property_added_rule_detection() {
+        let base = create_test_schema(None);
+        let mut current = create_test_schema(None);
+
+        // Add a property to current that doesn't exist in base
+        current.properties.insert(
+            "email".to_string(),
+            oas3::spec::ObjectOrReference::Object(create_test_schema(Some(SchemaTypeSet::Single(
+                SchemaType::String,
+            )))),
+        );
+
+        let detected = PropertyAddedRule::detect("User", "", Some(&base), Some(&current));
+
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].name(), "PropertyAdded");
+        assert_eq!(detected[0].property_name, "email");
+        assert_eq!(detected[0].change_level(), ChangeLevel::Change);
+    }
+
+    #[test]
+    fn test_property_removed_rule_detection_optional() {
+        let mut base = create_test_schema(None);
+        let current = create_test_schema(None);
+
+        // Add a property to base that doesn't exist in current (and it's not required)
+        base.properties.insert(
+            "email".to_string(),
+            oas3::spec::ObjectOrReference::Object(create_test_schema(Some(SchemaTypeSet::Single(
+                SchemaType::String,
+            )))),
+        );
 
         let detected = PropertyRemovedRule::detect("User", "", Some(&base), Some(&current));
 
         assert_eq!(detected.len(), 1);
         assert_eq!(detected[0].name(), "PropertyRemoved");
         assert_eq!(detected[0].property_name, "email");
-        assert_eq!(detected[0].was_required, false);
-        assert_eq!(detected[0].totally_removed, true); // Property is completely removed
+        assert!(!detected[0].was_required);
+        assert!(detected[0].totally_removed); // Property is completely removed
         assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
     }
 
@@ -876,8 +2220,8 @@ mod tests {
         assert_eq!(detected.len(), 1);
         assert_eq!(detected[0].name(), "RequiredPropertyRemoved");
         assert_eq!(detected[0].property_name, "email");
-        assert_eq!(detected[0].was_required, true);
-        assert_eq!(detected[0].totally_removed, true); // Property is completely removed
+        assert!(detected[0].was_required);
+        assert!(detected[0].totally_removed); // Property is completely removed
         assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
     }
 
@@ -967,8 +2311,8 @@ mod tests {
 
         // Check that all are marked as totally_removed
         for rule in &detected {
-            assert_eq!(
-                rule.totally_removed, true,
+            assert!(
+                rule.totally_removed,
                 "All removed properties should be marked as totally_removed"
             );
             assert_eq!(
@@ -983,19 +2327,19 @@ mod tests {
             .iter()
             .find(|r| r.property_name == "email")
             .unwrap();
-        assert_eq!(email_rule.was_required, true);
+        assert!(email_rule.was_required);
 
         let phone_rule = detected
             .iter()
             .find(|r| r.property_name == "phone")
             .unwrap();
-        assert_eq!(phone_rule.was_required, true);
+        assert!(phone_rule.was_required);
 
         let address_rule = detected
             .iter()
             .find(|r| r.property_name == "address")
             .unwrap();
-        assert_eq!(address_rule.was_required, false);
+        assert!(!address_rule.was_required);
     }
 
     #[test]
@@ -1046,7 +2390,7 @@ mod tests {
         assert_eq!(detected.len(), 1);
         assert_eq!(detected[0].property_path, "address");
         assert_eq!(detected[0].property_name, "nested_field");
-        assert_eq!(detected[0].totally_removed, true);
+        assert!(detected[0].totally_removed);
     }
 
     #[test]
@@ -1093,10 +2437,11 @@ mod tests {
             serde_json::Value::String("inactive".to_string()),
         ];
 
-        let detected = EnumValuesAddedRule::detect("Status", "", Some(&base), Some(&current));
+        let detected = EnumChangedRule::detect("Status", "", Some(&base), Some(&current));
 
         assert_eq!(detected.len(), 1);
-        assert_eq!(detected[0].name(), "EnumValuesAdded");
+        assert_eq!(detected[0].name(), "EnumChanged");
+        assert_eq!(detected[0].added, vec![serde_json::json!("inactive")]);
         assert!(detected[0].description().contains("inactive"));
         assert_eq!(detected[0].change_level(), ChangeLevel::Change);
     }
@@ -1112,14 +2457,54 @@ mod tests {
         ];
         current.enum_values = vec![serde_json::Value::String("active".to_string())];
 
-        let detected = EnumValuesRemovedRule::detect("Status", "", Some(&base), Some(&current));
+        let detected = EnumChangedRule::detect("Status", "", Some(&base), Some(&current));
 
         assert_eq!(detected.len(), 1);
-        assert_eq!(detected[0].name(), "EnumValuesRemoved");
+        assert_eq!(detected[0].removed, vec![serde_json::json!("pending")]);
         assert!(detected[0].description().contains("pending"));
         assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
     }
 
+    #[test]
+    fn test_enum_rename_and_type_change_detection() {
+        let mut base = create_test_schema(None);
+        let mut current = create_test_schema(None);
+
+        base.enum_values = vec![
+            serde_json::json!("ACTIVE"),
+            serde_json::json!("IN_PROGRESS"),
+            serde_json::json!(1),
+            serde_json::json!("archived"),
+        ];
+        current.enum_values = vec![
+            serde_json::json!("ENABLED"),
+            serde_json::json!("draft"),
+            serde_json::json!("1"),
+            serde_json::json!("inProgress"),
+        ];
+
+        let detected = EnumChangedRule::detect("Status", "", Some(&base), Some(&current));
+
+        assert_eq!(detected.len(), 1);
+        assert_eq!(
+            detected[0].renamed,
+            vec![
+                (serde_json::json!("ACTIVE"), serde_json::json!("ENABLED")),
+                (
+                    serde_json::json!("IN_PROGRESS"),
+                    serde_json::json!("inProgress")
+                ),
+            ]
+        );
+        assert_eq!(
+            detected[0].type_changed,
+            vec![(serde_json::json!(1), serde_json::json!("1"))]
+        );
+        assert_eq!(detected[0].removed, vec![serde_json::json!("archived")]);
+        assert_eq!(detected[0].added, vec![serde_json::json!("draft")]);
+        assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
+    }
+
     #[test]
     fn test_format_changed_rule_detection() {
         let mut base = create_test_schema(None);
@@ -1147,8 +2532,8 @@ mod tests {
 
         assert_eq!(detected.len(), 1);
         assert_eq!(detected[0].name(), "NullableChanged");
-        assert_eq!(detected[0].old_nullable, true);
-        assert_eq!(detected[0].new_nullable, false);
+        assert!(detected[0].old_nullable);
+        assert!(!detected[0].new_nullable);
         assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
     }
 
@@ -1161,8 +2546,8 @@ mod tests {
         let detected = NullableChangedRule::detect("User", "email", Some(&base), Some(&current));
 
         assert_eq!(detected.len(), 1);
-        assert_eq!(detected[0].old_nullable, false);
-        assert_eq!(detected[0].new_nullable, true);
+        assert!(!detected[0].old_nullable);
+        assert!(detected[0].new_nullable);
         assert_eq!(detected[0].change_level(), ChangeLevel::Warning);
     }
 
@@ -1192,4 +2577,280 @@ mod tests {
         assert!(prop_names.contains(&"email"));
         assert!(prop_names.contains(&"age"));
     }
+
+    #[test]
+    fn test_type_array_with_null_is_not_a_type_change() {
+        // 3.0 `nullable: true` carried as an extension is the same as "null" in a type array
+        let mut base = create_test_schema(Some(SchemaTypeSet::Single(SchemaType::String)));
+        base.extensions
+            .insert("apidrift-nullable".to_string(), serde_json::json!(true));
+        let current = create_test_schema(Some(SchemaTypeSet::Multiple(vec![
+            SchemaType::Null,
+            SchemaType::String,
+        ])));
+
+        assert!(TypeChangedRule::detect("User", "email", Some(&base), Some(&current)).is_empty());
+        assert!(
+            NullableChangedRule::detect("User", "email", Some(&base), Some(&current)).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_any_of_with_null_branch_is_nullable() {
+        let branch = |schema_type| {
+            ObjectOrReference::Object(create_test_schema(Some(SchemaTypeSet::Single(schema_type))))
+        };
+        let base = create_test_schema(None);
+        let mut current = create_test_schema(None);
+        current.any_of = vec![branch(SchemaType::String), branch(SchemaType::Null)];
+        assert!(is_nullable(&current));
+
+        let detected = NullableChangedRule::detect("User", "email", Some(&base), Some(&current));
+        assert_eq!(detected.len(), 1);
+        assert!(detected[0].new_nullable);
+
+        let mut one_of = create_test_schema(None);
+        one_of.one_of = vec![branch(SchemaType::Null), branch(SchemaType::Integer)];
+        assert!(is_nullable(&one_of));
+
+        // A null branch among several alternatives is not plain nullability
+        current.any_of.push(branch(SchemaType::Integer));
+        assert!(!is_nullable(&current));
+    }
+
+    #[test]
+    fn test_const_changed_rule_detection() {
+        let mut base = create_test_schema(None);
+        base.const_value = Some(serde_json::json!("v1"));
+        let mut current = base.clone();
+        current.const_value = Some(serde_json::json!("v2"));
+
+        let detected = ConstChangedRule::detect("Event", "version", Some(&base), Some(&current));
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
+
+        current.const_value = None;
+        let detected = ConstChangedRule::detect("Event", "version", Some(&base), Some(&current));
+        assert_eq!(detected[0].change_level(), ChangeLevel::Change);
+    }
+
+    #[test]
+    fn test_prefix_items_appended_is_warning() {
+        let item = |schema_type| {
+            oas3::spec::ObjectOrReference::Object(create_test_schema(Some(SchemaTypeSet::Single(
+                schema_type,
+            ))))
+        };
+        let mut base = create_test_schema(None);
+        base.prefix_items = vec![item(SchemaType::String)];
+        let mut current = base.clone();
+        current.prefix_items.push(item(SchemaType::Integer));
+
+        let detected = PrefixItemsChangedRule::detect("Point", "", Some(&base), Some(&current));
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].change_level(), ChangeLevel::Warning);
+
+        current.prefix_items[0] = item(SchemaType::Number);
+        let detected = PrefixItemsChangedRule::detect("Point", "", Some(&base), Some(&current));
+        assert_eq!(detected[0].changed_positions, vec![0]);
+        assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
+    }
+
+    #[test]
+    fn test_carried_keyword_rules() {
+        let base = create_test_schema(None);
+        let mut current = base.clone();
+        current.extensions.insert(
+            "apidrift-dependentRequired".to_string(),
+            serde_json::json!({"card": ["billingAddress"]}),
+        );
+        current.extensions.insert(
+            "apidrift-unevaluatedProperties".to_string(),
+            serde_json::json!(false),
+        );
+        current.extensions.insert(
+            "apidrift-if".to_string(),
+            serde_json::json!({"required": ["card"]}),
+        );
+
+        let detected =
+            DependentRequiredChangedRule::detect("Payment", "", Some(&base), Some(&current));
+        assert_eq!(detected[0].added, vec!["card -> billingAddress"]);
+        assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
+
+        let detected =
+            UnevaluatedPropertiesChangedRule::detect("Payment", "", Some(&base), Some(&current));
+        assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
+
+        let detected =
+            ConditionalSchemaChangedRule::detect("Payment", "", Some(&base), Some(&current));
+        assert_eq!(detected[0].keywords, vec!["if"]);
+        assert_eq!(detected[0].change_level(), ChangeLevel::Warning);
+    }
+
+    #[test]
+    fn test_additional_properties_rules() {
+        let value_schema = |ref_path: &str| {
+            Some(Schema::Object(Box::new(ObjectOrReference::Ref {
+                ref_path: ref_path.to_string(),
+                summary: None,
+                description: None,
+            })))
+        };
+        let base = create_test_schema(None);
+        let mut current = base.clone();
+
+        // Unset and true both allow anything
+        current.additional_properties = Some(Schema::Boolean(BooleanSchema(true)));
+        assert!(
+            AdditionalPropertiesChangedRule::detect("Item", "", Some(&base), Some(&current))
+                .is_empty()
+        );
+
+        current.additional_properties = Some(Schema::Boolean(BooleanSchema(false)));
+        let closed =
+            AdditionalPropertiesClosedRule::detect("Item", "", Some(&base), Some(&current));
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].change_level(), ChangeLevel::Breaking);
+        assert!(
+            AdditionalPropertiesChangedRule::detect("Item", "", Some(&base), Some(&current))
+                .is_empty()
+        );
+
+        current.additional_properties = value_schema("#/components/schemas/Tag");
+        let changed =
+            AdditionalPropertiesChangedRule::detect("Item", "tags", Some(&base), Some(&current));
+        assert_eq!(changed.len(), 1);
+        assert_eq!(
+            changed[0].new_value,
+            AdditionalProperties::Schema(Some("Tag".into()))
+        );
+        assert_eq!(changed[0].change_level(), ChangeLevel::Warning);
+
+        let mut reopened = current.clone();
+        reopened.additional_properties = None;
+        let changed = AdditionalPropertiesChangedRule::detect(
+            "Item",
+            "tags",
+            Some(&current),
+            Some(&reopened),
+        );
+        assert_eq!(changed[0].change_level(), ChangeLevel::Change);
+    }
+
+    #[test]
+    fn test_read_only_and_write_only_changes() {
+        let base = create_test_schema(None);
+        let mut current = base.clone();
+        current.read_only = Some(true);
+
+        let detected = ReadOnlyChangedRule::detect("User", "id", Some(&base), Some(&current));
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].change_level(), ChangeLevel::Warning);
+        assert!(WriteOnlyChangedRule::detect("User", "id", Some(&base), Some(&current)).is_empty());
+
+        current.read_only = None;
+        current.write_only = Some(true);
+        let detected = WriteOnlyChangedRule::detect("User", "id", Some(&base), Some(&current));
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
+
+        let detected = WriteOnlyChangedRule::detect("User", "id", Some(&current), Some(&base));
+        assert_eq!(detected[0].change_level(), ChangeLevel::Change);
+    }
+
+    #[test]
+    fn test_default_changed_rule_renders_json() {
+        let mut base = create_test_schema(None);
+        base.default = Some(serde_json::json!("draft"));
+        let mut current = base.clone();
+        current.default = Some(serde_json::json!({"status": "published"}));
+
+        let detected = DefaultChangedRule::detect("Post", "status", Some(&base), Some(&current));
+        assert_eq!(detected.len(), 1);
+        assert_eq!(
+            detected[0].description(),
+            r#"Default value changed from "draft" to {"status":"published"}"#
+        );
+        assert_eq!(detected[0].change_level(), ChangeLevel::Warning);
+    }
+
+    #[test]
+    fn test_example_changed_rule_detection() {
+        let mut base = create_test_schema(None);
+        base.example = Some(serde_json::json!(1));
+        let mut current = base.clone();
+        assert!(ExampleChangedRule::detect("Post", "id", Some(&base), Some(&current)).is_empty());
+
+        current.example = None;
+        current.examples = vec![serde_json::json!(2)];
+        let detected = ExampleChangedRule::detect("Post", "id", Some(&base), Some(&current));
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].description(), "Example changed from 1 to 2");
+        assert_eq!(detected[0].change_level(), ChangeLevel::Change);
+    }
+
+    #[test]
+    fn test_discriminator_mapping_changes() {
+        let subtype = |name: &str| oas3::spec::ObjectOrReference::Ref {
+            ref_path: format!("#/components/schemas/{}", name),
+            summary: None,
+            description: None,
+        };
+        let mut base = create_test_schema(None);
+        base.one_of = vec![subtype("Cat"), subtype("Dog")];
+        base.discriminator = Some(oas3::spec::Discriminator {
+            property_name: "petType".to_string(),
+            mapping: Some(BTreeMap::from([(
+                "dog".to_string(),
+                "#/components/schemas/Dog".to_string(),
+            )])),
+        });
+        assert_eq!(
+            discriminator_mapping(&base),
+            BTreeMap::from([
+                ("Cat".to_string(), "Cat".to_string()),
+                ("dog".to_string(), "Dog".to_string()),
+            ])
+        );
+
+        let mut current = base.clone();
+        current.one_of = vec![subtype("Cat"), subtype("Wolf")];
+        current.discriminator.as_mut().unwrap().mapping =
+            Some(BTreeMap::from([("dog".to_string(), "Wolf".to_string())]));
+
+        let detected =
+            DiscriminatorMappingChangedRule::detect("Pet", "", Some(&base), Some(&current));
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].value, "dog");
+        assert_eq!(detected[0].new_target.as_deref(), Some("Wolf"));
+        assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
+
+        current.discriminator.as_mut().unwrap().property_name = "kind".to_string();
+        let detected =
+            DiscriminatorPropertyChangedRule::detect("Pet", "", Some(&base), Some(&current));
+        assert_eq!(detected.len(), 1);
+        assert_eq!(detected[0].change_level(), ChangeLevel::Breaking);
+    }
+
+    #[test]
+    fn test_change_payload() {
+        let base = create_test_schema(Some(SchemaTypeSet::Single(SchemaType::String)));
+        let current = create_test_schema(Some(SchemaTypeSet::Single(SchemaType::Integer)));
+        let detected = TypeChangedRule::detect("User", "age", Some(&base), Some(&current));
+
+        let change = detected[0].change();
+        assert_eq!(change.rule, "TypeChanged");
+        assert_eq!(change.schema_name.as_deref(), Some("User"));
+        assert_eq!(change.path.as_deref(), Some("age"));
+        assert_eq!(change.old_value, Some(serde_json::json!("string")));
+        assert_eq!(change.new_value, Some(serde_json::json!("integer")));
+
+        let serialized = serde_json::to_value(&change).unwrap();
+        assert_eq!(serialized["level"], "Breaking");
+        assert_eq!(
+            serialized["anchor"],
+            serde_json::json!({"kind": "PropertyType", "path": "age"})
+        );
+    }
 }
@@ -0,0 +1,127 @@
+use crate::rules::schema::is_nullable;
+use oas3::spec::{ObjectOrReference, ObjectSchema, Schema, SchemaType, SchemaTypeSet};
+
+/// Human-readable type of a schema or reference, e.g. `User` for a `$ref`
+pub fn type_name_of(schema: &ObjectOrReference<ObjectSchema>) -> String {
+    match schema {
+        ObjectOrReference::Ref { ref_path, .. } => ref_name(ref_path),
+        ObjectOrReference::Object(schema) => type_name(schema),
+    }
+}
+
+/// Human-readable type of a schema, e.g. `array<User>`, `string (date-time)`,
+/// `integer | null` or `oneOf<Cat, Dog>`
+pub fn type_name(schema: &ObjectSchema) -> String {
+    let types: Vec<SchemaType> = match &schema.schema_type {
+        Some(SchemaTypeSet::Single(schema_type)) => vec![*schema_type],
+        Some(SchemaTypeSet::Multiple(types)) => types.clone(),
+        None => vec![],
+    };
+
+    let mut names: Vec<String> = types
+        .iter()
+        .filter(|schema_type| **schema_type != SchemaType::Null)
+        .map(|schema_type| single_type_name(schema, *schema_type))
+        .collect();
+
+    if names.is_empty() {
+        names.push(composition_name(schema).unwrap_or_else(|| "any".to_string()));
+    }
+    if is_nullable(schema) {
+        names.push("null".to_string());
+    }
+    names.join(" | ")
+}
+
+fn single_type_name(schema: &ObjectSchema, schema_type: SchemaType) -> String {
+    let name = match schema_type {
+        SchemaType::Boolean => "boolean".to_string(),
+        SchemaType::Integer => "integer".to_string(),
+        SchemaType::Number => "number".to_string(),
+        SchemaType::String => "string".to_string(),
+        SchemaType::Null => "null".to_string(),
+        SchemaType::Array => match &schema.items {
+            Some(items) => format!("array<{}>", schema_name(items)),
+            None => "array".to_string(),
+        },
+        SchemaType::Object => match &schema.additional_properties {
+            Some(values @ Schema::Object(_)) => format!("map<string, {}>", schema_name(values)),
+            _ => "object".to_string(),
+        },
+    };
+
+    match &schema.format {
+        Some(format) if !matches!(schema_type, SchemaType::Array | SchemaType::Object) => {
+            format!("{} ({})", name, format)
+        }
+        _ => name,
+    }
+}
+
+/// `oneOf<Cat, Dog>` style name of a composed schema without a type
+fn composition_name(schema: &ObjectSchema) -> Option<String> {
+    let (keyword, members) = [
+        ("oneOf", &schema.one_of),
+        ("anyOf", &schema.any_of),
+        ("allOf", &schema.all_of),
+    ]
+    .into_iter()
+    .find(|(_, members)| !members.is_empty())?;
+
+    let names: Vec<String> = members.iter().map(type_name_of).collect();
+    Some(format!("{}<{}>", keyword, names.join(", ")))
+}
+
+fn schema_name(schema: &Schema) -> String {
+    match schema {
+        Schema::Boolean(_) => "any".to_string(),
+        Schema::Object(schema) => type_name_of(schema),
+    }
+}
+
+fn ref_name(ref_path: &str) -> String {
+    ref_path.rsplit('/').next().unwrap_or(ref_path).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema(value: serde_json::Value) -> ObjectSchema {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_type_names() {
+        let cases = [
+            (
+                serde_json::json!({"type": "array", "items": {"$ref": "#/components/schemas/User"}}),
+                "array<User>",
+            ),
+            (
+                serde_json::json!({"type": "string", "format": "date-time"}),
+                "string (date-time)",
+            ),
+            (
+                serde_json::json!({"type": ["integer", "null"]}),
+                "integer | null",
+            ),
+            (
+                serde_json::json!({"oneOf": [
+                    {"$ref": "#/components/schemas/Cat"},
+                    {"$ref": "#/components/schemas/Dog"}
+                ]}),
+                "oneOf<Cat, Dog>",
+            ),
+            (
+                serde_json::json!({"type": "object", "additionalProperties": {"type": "integer"}}),
+                "map<string, integer>",
+            ),
+            (serde_json::json!({}), "any"),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(type_name(&schema(value)), expected);
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+/// Prefix of the git input syntax: `git:REV:path/to/openapi.yaml`
+const GIT_PREFIX: &str = "git:";
+
+/// Input/output name standing for stdin/stdout
+pub const STDIO_NAME: &str = "-";
+
+/// Location an OpenAPI specification is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpecSource {
+    /// Plain file on disk
+    File(PathBuf),
+    /// File at a given revision of the local git repository.
+    /// Path follows git `REV:path` rules: relative to the repository root,
+    /// or to the current directory when it starts with `./` or `../`
+    Git { rev: String, path: PathBuf },
+    /// Content piped to stdin
+    Stdin,
+}
+
+impl SpecSource {
+    /// Path of the specification file (inside the repository for git sources)
+    pub fn path(&self) -> &Path {
+        match self {
+            SpecSource::File(path) => path,
+            SpecSource::Git { path, .. } => path,
+            SpecSource::Stdin => Path::new(STDIO_NAME),
+        }
+    }
+
+    /// Read the same file at the given git revision.
+    /// Relative file paths are resolved against the current directory,
+    /// absolute ones are made relative to the repository root
+    pub fn at_revision(&self, rev: &str) -> SpecSource {
+        let path = match self {
+            SpecSource::File(path) if path.is_relative() => match path.components().next() {
+                Some(Component::CurDir) | Some(Component::ParentDir) => path.clone(),
+                _ => Path::new(".").join(path),
+            },
+            SpecSource::File(path) => repo_relative(path).unwrap_or_else(|| path.clone()),
+            other => other.path().to_path_buf(),
+        };
+
+        SpecSource::Git {
+            rev: rev.to_string(),
+            path,
+        }
+    }
+
+    /// Source of a file referenced from this one by a relative path.
+    /// Files referenced from stdin are resolved against the current directory
+    pub fn sibling(&self, relative: &Path) -> SpecSource {
+        let join = |path: &Path| normalize(&path.parent().unwrap_or(Path::new("")).join(relative));
+
+        match self {
+            SpecSource::File(path) => SpecSource::File(join(path)),
+            SpecSource::Git { rev, path } => SpecSource::Git {
+                rev: rev.clone(),
+                path: join(path),
+            },
+            SpecSource::Stdin => SpecSource::File(relative.to_path_buf()),
+        }
+    }
+
+    /// Check that the source can be read before parsing
+    pub fn exists(&self) -> bool {
+        match self {
+            SpecSource::File(path) => path.exists(),
+            // Checked by git itself while reading
+            SpecSource::Git { .. } | SpecSource::Stdin => true,
+        }
+    }
+
+    /// Read raw specification content
+    pub fn read_to_string(&self) -> Result<String, String> {
+        match self {
+            SpecSource::File(path) => fs::read_to_string(path).map_err(|err| {
+                format!("Failed to read file \"{}\". Error: {}", path.display(), err)
+            }),
+            SpecSource::Git { rev, path } => {
+                let object = format!("{}:{}", rev, path.display());
+                let output = Command::new("git")
+                    .arg("show")
+                    .arg("--end-of-options")
+                    .arg(&object)
+                    .output()
+                    .map_err(|err| format!("Failed to run git. Error: {}", err))?;
+
+                if !output.status.success() {
+                    return Err(format!(
+                        "Failed to read \"{}\" from git. Error: {}",
+                        object,
+                        String::from_utf8_lossy(&output.stderr).trim()
+                    ));
+                }
+
+                String::from_utf8(output.stdout).map_err(|err| {
+                    format!("File \"{}\" is not valid UTF-8. Error: {}", object, err)
+                })
+            }
+            SpecSource::Stdin => {
+                let mut content = String::new();
+                io::stdin()
+                    .read_to_string(&mut content)
+                    .map_err(|err| format!("Failed to read from stdin. Error: {}", err))?;
+                Ok(content)
+            }
+        }
+    }
+}
+
+/// Path of a file relative to the root of the git repository it is in,
+/// `None` when it is outside of a repository
+fn repo_relative(path: &Path) -> Option<PathBuf> {
+    let dir = path.parent()?;
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["rev-parse", "--show-toplevel"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let root = fs::canonicalize(String::from_utf8(output.stdout).ok()?.trim()).ok()?;
+    let dir = fs::canonicalize(dir).ok()?;
+    let file_name = path.file_name()?;
+    Some(dir.strip_prefix(root).ok()?.join(file_name))
+}
+
+/// Collapse `..` segments without touching the filesystem (git paths don't exist on disk).
+/// A leading `./` is kept, since git treats it as relative to the current directory
+fn normalize(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir if result.as_os_str().is_empty() => result.push(component),
+            Component::CurDir => {}
+            Component::ParentDir => match result.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    result.pop();
+                }
+                _ => result.push(component),
+            },
+            other => result.push(other),
+        }
+    }
+    result
+}
+
+impl FromStr for SpecSource {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input == STDIO_NAME {
+            return Ok(SpecSource::Stdin);
+        }
+
+        let Some(reference) = input.strip_prefix(GIT_PREFIX) else {
+            return Ok(SpecSource::File(PathBuf::from(input)));
+        };
+
+        match reference.split_once(':') {
+            Some((rev, path)) if !rev.is_empty() && !path.is_empty() => Ok(SpecSource::Git {
+                rev: rev.to_string(),
+                path: PathBuf::from(path),
+            }),
+            _ => Err(format!(
+                "Invalid git input '{}'. Expected format: git:REV:path/to/openapi.yaml",
+                input
+            )),
+        }
+    }
+}
+
+impl fmt::Display for SpecSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpecSource::File(path) => write!(f, "{}", path.display()),
+            SpecSource::Git { rev, path } => write!(f, "{}{}:{}", GIT_PREFIX, rev, path.display()),
+            SpecSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_file_source() {
+        let source: SpecSource = "specs/openapi.yaml".parse().unwrap();
+        assert_eq!(
+            source,
+            SpecSource::File(PathBuf::from("specs/openapi.yaml"))
+        );
+    }
+
+    #[test]
+    fn test_parse_stdin_source() {
+        let source: SpecSource = "-".parse().unwrap();
+        assert_eq!(source, SpecSource::Stdin);
+        assert_eq!(source.to_string(), "<stdin>");
+    }
+
+    #[test]
+    fn test_parse_git_source() {
+        let source: SpecSource = "git:origin/main:specs/openapi.yaml".parse().unwrap();
+        assert_eq!(
+            source,
+            SpecSource::Git {
+                rev: "origin/main".to_string(),
+                path: PathBuf::from("specs/openapi.yaml"),
+            }
+        );
+        assert_eq!(source.to_string(), "git:origin/main:specs/openapi.yaml");
+    }
+
+    #[test]
+    fn test_parse_invalid_git_source() {
+        assert!("git:main".parse::<SpecSource>().is_err());
+        assert!("git::openapi.yaml".parse::<SpecSource>().is_err());
+    }
+
+    #[test]
+    fn test_sibling_resolves_relative_to_spec_dir() {
+        let source = SpecSource::File(PathBuf::from("specs/openapi.yaml"));
+        assert_eq!(
+            source.sibling(Path::new("../common/user.yaml")),
+            SpecSource::File(PathBuf::from("common/user.yaml"))
+        );
+
+        let source = SpecSource::File(PathBuf::from("specs/openapi.yaml")).at_revision("main");
+        assert_eq!(
+            source.sibling(Path::new("schemas/user.yaml")),
+            SpecSource::Git {
+                rev: "main".to_string(),
+                path: PathBuf::from("./specs/schemas/user.yaml"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_at_revision_keeps_path_relative_to_current_dir() {
+        let source = SpecSource::File(PathBuf::from("specs/openapi.yaml"));
+        assert_eq!(
+            source.at_revision("main"),
+            SpecSource::Git {
+                rev: "main".to_string(),
+                path: PathBuf::from("./specs/openapi.yaml"),
+            }
+        );
+
+        let source = SpecSource::File(PathBuf::from("../openapi.yaml"));
+        assert_eq!(
+            source.at_revision("v1.0.0").path(),
+            Path::new("../openapi.yaml")
+        );
+    }
+
+    #[test]
+    fn test_at_revision_makes_absolute_path_relative_to_repo_root() {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let source = SpecSource::File(manifest_dir.join("src/source.rs"));
+        let Some(manifest) = repo_relative(&manifest_dir.join("Cargo.toml")) else {
+            // Not built from a git checkout
+            return;
+        };
+
+        let expected = manifest.parent().unwrap().join("src/source.rs");
+        assert_eq!(source.at_revision("HEAD").path(), expected);
+    }
+}
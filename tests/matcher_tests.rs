@@ -1,4 +1,5 @@
-use apidrift::matcher::SchemaMatcher;
+use apidrift::filter::{filter_by_min_level, MatchFilter};
+use apidrift::matcher::{RouteMatcher, RouteSideLevels, SchemaMatcher};
 use apidrift::ChangeLevel;
 use oas3::OpenApiV3Spec;
 
@@ -98,7 +99,7 @@ fn test_product_schema_changes() {
         .violations
         .iter()
         .any(|v| {
-            v.name() == "EnumValuesAdded" && 
+            v.name() == "EnumChanged" && 
             matches!(v.context(), apidrift::rules::ChangeAnchor::EnumValues(ref path) if path.contains("category"))
         });
     assert!(has_enum_added, "Should detect enum changes");
@@ -123,7 +124,7 @@ fn test_status_enum_breaking_change() {
     let has_enum_removed = status_result
         .violations
         .iter()
-        .any(|v| v.name() == "EnumValuesRemoved");
+        .any(|v| v.name() == "EnumChanged" && v.description().contains("removed"));
     assert!(has_enum_removed, "Should detect removed enum values");
 }
 
@@ -206,3 +207,483 @@ fn test_change_level_hierarchy() {
         }
     }
 }
+
+#[test]
+fn test_excluded_schemas_are_skipped() {
+    let base = load_test_schema("tests/base_test_schema.json");
+    let current = load_test_schema("tests/current_test_schema.json");
+
+    let base_schemas = &base.components.as_ref().unwrap().schemas;
+    let current_schemas = &current.components.as_ref().unwrap().schemas;
+
+    let filter = MatchFilter::new(&[], &[], &["New*".to_string(), "User".to_string()]).unwrap();
+    let matcher =
+        SchemaMatcher::new(base_schemas, current_schemas, &base, &current).with_filter(filter);
+    let results = matcher.match_schemas();
+
+    assert!(results
+        .iter()
+        .all(|r| r.name != "NewModel" && r.name != "User"));
+    assert!(results.iter().any(|r| r.name == "Product"));
+}
+
+#[test]
+fn test_nullable_migration_to_openapi_3_1_is_not_reported() {
+    let (base, _) = apidrift::loader::parse_spec(
+        r#"
+openapi: 3.0.3
+info: {title: Test, version: "1"}
+paths: {}
+components:
+  schemas:
+    User:
+      type: object
+      properties:
+        nickname: {type: string, nullable: true}
+        tags: {type: array, items: {type: string}, nullable: true}
+"#,
+        std::path::Path::new("base.yaml"),
+    )
+    .unwrap();
+    let (current, _) = apidrift::loader::parse_spec(
+        r#"
+openapi: 3.1.0
+info: {title: Test, version: "1"}
+paths: {}
+components:
+  schemas:
+    User:
+      type: object
+      properties:
+        nickname: {type: [string, "null"]}
+        tags: {type: ["null", array], items: {type: string}}
+"#,
+        std::path::Path::new("current.yaml"),
+    )
+    .unwrap();
+
+    let base_schemas = &base.components.as_ref().unwrap().schemas;
+    let current_schemas = &current.components.as_ref().unwrap().schemas;
+    let matcher = SchemaMatcher::new(base_schemas, current_schemas, &base, &current);
+
+    assert!(matcher.match_schemas().is_empty());
+}
+
+fn parse_yaml_spec(content: &str) -> OpenApiV3Spec {
+    apidrift::loader::parse_spec(content, std::path::Path::new("spec.yaml"))
+        .unwrap()
+        .0
+}
+
+/// Spec where `Item` is sent to POST /items and returned from GET /items
+fn map_spec(item: &str) -> OpenApiV3Spec {
+    parse_yaml_spec(&format!(
+        r#"
+openapi: 3.0.3
+info: {{title: Test, version: "1"}}
+paths:
+  /items:
+    post:
+      requestBody:
+        content:
+          application/json:
+            schema: {{$ref: '#/components/schemas/Item'}}
+      responses:
+        "204": {{description: Created}}
+    get:
+      responses:
+        "200":
+          description: Item
+          content:
+            application/json:
+              schema: {{$ref: '#/components/schemas/Item'}}
+components:
+  schemas:
+    Item: {item}
+"#
+    ))
+}
+
+#[test]
+fn test_additional_properties_value_schema_is_compared() {
+    let base = map_spec(
+        "{type: object, properties: {metadata: {type: object, additionalProperties: {type: string}}}}",
+    );
+    let current = map_spec(
+        "{type: object, properties: {metadata: {type: object, additionalProperties: {type: integer}}}}",
+    );
+
+    let base_schemas = &base.components.as_ref().unwrap().schemas;
+    let current_schemas = &current.components.as_ref().unwrap().schemas;
+    let results =
+        SchemaMatcher::new(base_schemas, current_schemas, &base, &current).match_schemas();
+
+    assert_eq!(results.len(), 1);
+    let violation = &results[0].violations[0];
+    assert_eq!(violation.name(), "TypeChanged");
+    assert_eq!(violation.context().property_path(), Some("metadata.{*}"));
+}
+
+#[test]
+fn test_closing_additional_properties_breaks_requests_only() {
+    let base = map_spec("{type: object, properties: {name: {type: string}}}");
+    let current =
+        map_spec("{type: object, properties: {name: {type: string}}, additionalProperties: false}");
+
+    let base_schemas = &base.components.as_ref().unwrap().schemas;
+    let current_schemas = &current.components.as_ref().unwrap().schemas;
+    let schema_results =
+        SchemaMatcher::new(base_schemas, current_schemas, &base, &current).match_schemas();
+    assert_eq!(
+        schema_results[0].violations[0].name(),
+        "AdditionalPropertiesClosed"
+    );
+
+    let route_results =
+        RouteMatcher::new(&base, &current).match_routes_with_schema_violations(&schema_results);
+    let level_of = |name: &str| {
+        route_results
+            .iter()
+            .find(|r| r.name == name)
+            .map(|r| r.change_level.clone())
+    };
+
+    assert_eq!(level_of("POST /items"), Some(ChangeLevel::Breaking));
+    assert_eq!(level_of("GET /items"), Some(ChangeLevel::Change));
+}
+
+#[test]
+fn test_read_only_property_changes_skip_requests() {
+    let base = map_spec("{type: object, properties: {id: {type: string, readOnly: true}}}");
+    let current = map_spec(
+        "{type: object, required: [id], properties: {id: {type: string, readOnly: true}, secret: {type: string, writeOnly: true}}}",
+    );
+
+    let base_schemas = &base.components.as_ref().unwrap().schemas;
+    let current_schemas = &current.components.as_ref().unwrap().schemas;
+    let schema_results =
+        SchemaMatcher::new(base_schemas, current_schemas, &base, &current).match_schemas();
+    // Only readers see the readOnly id, a new required value doesn't break them
+    assert_eq!(schema_results[0].change_level, ChangeLevel::Change);
+
+    let route_results =
+        RouteMatcher::new(&base, &current).match_routes_with_schema_violations(&schema_results);
+    let get_level = route_results
+        .iter()
+        .find(|r| r.name == "GET /items")
+        .map(|r| r.change_level.clone());
+    assert_eq!(get_level, Some(ChangeLevel::Change));
+
+    let violations_of = |name: &str| -> Vec<String> {
+        route_results
+            .iter()
+            .filter(|r| r.name == name)
+            .flat_map(|r| r.violations.iter().map(|v| v.description()))
+            .collect()
+    };
+
+    // Clients never send the readOnly id, and never receive the writeOnly secret
+    let post = violations_of("POST /items");
+    assert!(post.iter().all(|d| !d.contains("'id'")));
+    assert!(post.iter().any(|d| d.contains("secret")));
+
+    let get = violations_of("GET /items");
+    assert!(get.iter().any(|d| d.contains("Required property 'id'")));
+    assert!(get.iter().all(|d| !d.contains("secret")));
+}
+
+#[test]
+fn test_discriminator_subtypes_are_compared_under_their_value() {
+    let spec = |bark_type: &str| {
+        parse_yaml_spec(&format!(
+            r#"
+openapi: 3.0.3
+info: {{title: Test, version: "1"}}
+paths: {{}}
+components:
+  schemas:
+    Pet:
+      oneOf:
+        - $ref: '#/components/schemas/Cat'
+        - $ref: '#/components/schemas/Dog'
+      discriminator:
+        propertyName: petType
+        mapping:
+          dog: '#/components/schemas/Dog'
+    Cat: {{type: object, properties: {{petType: {{type: string}}}}}}
+    Dog: {{type: object, properties: {{petType: {{type: string}}, bark: {{type: {bark_type}}}}}}}
+"#
+        ))
+    };
+    let base = spec("string");
+    let current = spec("boolean");
+
+    let base_schemas = &base.components.as_ref().unwrap().schemas;
+    let current_schemas = &current.components.as_ref().unwrap().schemas;
+    let results =
+        SchemaMatcher::new(base_schemas, current_schemas, &base, &current).match_schemas();
+
+    let pet = results.iter().find(|r| r.name == "Pet").unwrap();
+    assert_eq!(pet.violations.len(), 1);
+    assert_eq!(pet.violations[0].name(), "TypeChanged");
+    assert_eq!(
+        pet.violations[0].context().property_path(),
+        Some("{petType=dog}.bark")
+    );
+    assert!(results.iter().any(|r| r.name == "Dog"));
+}
+
+#[test]
+fn test_route_schema_changes_carry_location() {
+    let base = map_spec("{type: object, properties: {count: {type: string}}}");
+    let current = map_spec("{type: object, properties: {count: {type: integer}}}");
+
+    let base_schemas = &base.components.as_ref().unwrap().schemas;
+    let current_schemas = &current.components.as_ref().unwrap().schemas;
+    let schema_results =
+        SchemaMatcher::new(base_schemas, current_schemas, &base, &current).match_schemas();
+    let route_results =
+        RouteMatcher::new(&base, &current).match_routes_with_schema_violations(&schema_results);
+
+    let get = route_results
+        .iter()
+        .find(|r| r.name == "GET /items")
+        .unwrap();
+    let change = get.violations[0].change();
+    assert_eq!(change.rule, "TypeChanged");
+    assert_eq!(change.schema_name.as_deref(), Some("Item"));
+    assert_eq!(change.path.as_deref(), Some("count"));
+    assert_eq!(
+        change.location,
+        Some(apidrift::rules::ChangeLocation::Response {
+            status_code: "200".to_string(),
+            content_type: Some("application/json".to_string()),
+        })
+    );
+    assert_eq!(change.new_value, Some(serde_json::json!("integer")));
+}
+
+#[test]
+fn test_change_fingerprints_are_stable() {
+    let base = map_spec("{type: object, properties: {count: {type: string}}}");
+    let current =
+        map_spec("{type: object, properties: {count: {type: integer}, name: {type: string}}}");
+
+    let base_schemas = &base.components.as_ref().unwrap().schemas;
+    let current_schemas = &current.components.as_ref().unwrap().schemas;
+    let fingerprints = || -> Vec<String> {
+        SchemaMatcher::new(base_schemas, current_schemas, &base, &current)
+            .match_schemas()
+            .iter()
+            .flat_map(|r| r.violations.iter().map(|v| v.change().fingerprint(&r.name)))
+            .collect()
+    };
+
+    let first = fingerprints();
+    assert_eq!(first.len(), 2);
+    assert_ne!(first[0], first[1]);
+    assert_eq!(first[0].len(), 12);
+
+    let mut second = fingerprints();
+    second.sort();
+    let mut first_sorted = first.clone();
+    first_sorted.sort();
+    assert_eq!(first_sorted, second);
+}
+
+#[test]
+fn test_route_changes_carry_source_nodes() {
+    use apidrift::loader::{SourceMap, SpecFormat};
+
+    let base_content = r#"
+openapi: 3.0.3
+info: {title: Test, version: "1"}
+paths:
+  /items:
+    get:
+      responses:
+        "200": {description: OK}
+"#;
+    let current_content = r#"
+openapi: 3.0.3
+info: {title: Test, version: "1"}
+paths:
+  /items:
+    get:
+      responses:
+        "200": {description: OK}
+        "404": {description: Not found}
+"#;
+    let base = parse_yaml_spec(base_content);
+    let current = parse_yaml_spec(current_content);
+
+    let results = RouteMatcher::new(&base, &current)
+        .with_source_maps(
+            SourceMap::new(base_content, SpecFormat::Yaml),
+            SourceMap::new(current_content, SpecFormat::Yaml),
+        )
+        .match_routes();
+
+    let change = results[0].violations[0].change();
+    assert_eq!(change.rule, "ResponseStatusAdded");
+
+    let current_node = change.current_node.unwrap();
+    assert_eq!(current_node.pointer, "/paths/~1items/get/responses/404");
+    assert_eq!(
+        current_node.source.map(|s| (s.line, s.column)),
+        Some((9, 9))
+    );
+
+    // The new status has no node in the base spec, so the operation is used
+    assert_eq!(change.base_node.unwrap().pointer, "/paths/~1items/get");
+}
+
+/// Spec with an `orderPaid` webhook sending `Order` with the given query parameters
+fn webhook_spec(order: &str, parameters: &str) -> OpenApiV3Spec {
+    parse_yaml_spec(&format!(
+        r#"
+openapi: 3.1.0
+info: {{title: Test, version: "1"}}
+webhooks:
+  orderPaid:
+    post:
+      parameters: {parameters}
+      requestBody:
+        content:
+          application/json:
+            schema: {{$ref: '#/components/schemas/Order'}}
+      responses:
+        "200": {{description: Received}}
+components:
+  schemas:
+    Order: {order}
+"#
+    ))
+}
+
+#[test]
+fn test_webhook_required_changes_follow_direction() {
+    let base = webhook_spec(
+        "{type: object, required: [id, total], properties: {id: {type: string}, total: {type: number}}}",
+        "[{name: source, in: query, schema: {type: string}}]",
+    );
+    let current = webhook_spec(
+        "{type: object, required: [id, currency], properties: {id: {type: string}, total: {type: number}, currency: {type: string}}}",
+        "[{name: version, in: query, required: true, schema: {type: string}}]",
+    );
+
+    let base_schemas = &base.components.as_ref().unwrap().schemas;
+    let current_schemas = &current.components.as_ref().unwrap().schemas;
+    let schema_results =
+        SchemaMatcher::new(base_schemas, current_schemas, &base, &current).match_schemas();
+    let results = RouteMatcher::new(&base, &current)
+        .match_event_routes_with_schema_violations(&schema_results);
+
+    assert_eq!(results.len(), 1);
+    let level_of = |rule: &str| {
+        results[0]
+            .violations
+            .iter()
+            .map(|violation| violation.change())
+            .find(|change| change.rule == rule)
+            .map(|change| change.level)
+    };
+
+    // Consumers relied on `total` and the `source` parameter being sent
+    assert_eq!(
+        level_of("RequiredPropertyRemoved"),
+        Some(ChangeLevel::Breaking)
+    );
+    assert_eq!(level_of("ParameterRemoved"), Some(ChangeLevel::Breaking));
+    // New required values are simply received
+    assert_eq!(level_of("RequiredPropertyAdded"), Some(ChangeLevel::Change));
+    assert_eq!(
+        level_of("RequiredParameterAdded"),
+        Some(ChangeLevel::Change)
+    );
+}
+
+#[test]
+fn test_schema_changes_are_rated_by_their_routes_before_filtering() {
+    let base = map_spec(
+        "{type: object, required: [id, name], properties: {id: {type: string}, name: {type: string}}}",
+    );
+    let current = map_spec(
+        "{type: object, required: [id], properties: {id: {type: string}, name: {type: string}}}",
+    );
+
+    let base_schemas = &base.components.as_ref().unwrap().schemas;
+    let current_schemas = &current.components.as_ref().unwrap().schemas;
+    let schema_results =
+        SchemaMatcher::new(base_schemas, current_schemas, &base, &current).match_schemas();
+    // Senders may simply omit `name` now
+    assert_eq!(schema_results[0].change_level, ChangeLevel::Change);
+
+    let route_results =
+        RouteMatcher::new(&base, &current).match_routes_with_schema_violations(&schema_results);
+    let levels = RouteSideLevels::new(&route_results);
+    let schema_results = filter_by_min_level(levels.rate(schema_results), &ChangeLevel::Breaking);
+    let route_results = filter_by_min_level(route_results, &ChangeLevel::Breaking);
+
+    // Readers of GET /items relied on `name` being returned
+    assert_eq!(schema_results.len(), 1);
+    assert_eq!(schema_results[0].change_level, ChangeLevel::Breaking);
+    let names: Vec<&str> = route_results.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, vec!["GET /items"]);
+}
+
+/// Swagger 2.0 spec where `User` is sent to POST /users through a global body parameter
+fn swagger_spec(user: &str) -> OpenApiV3Spec {
+    parse_yaml_spec(&format!(
+        r#"
+swagger: "2.0"
+info: {{title: Test, version: "1"}}
+paths:
+  /users:
+    post:
+      consumes: [application/json]
+      parameters:
+        - $ref: '#/parameters/UserBody'
+      responses:
+        "204": {{description: Created}}
+parameters:
+  UserBody:
+    name: user
+    in: body
+    schema: {{$ref: '#/definitions/User'}}
+definitions:
+  User: {user}
+"#
+    ))
+}
+
+#[test]
+fn test_swagger_global_body_parameter_links_its_schema() {
+    let base = swagger_spec("{type: object, properties: {name: {type: string}}}");
+    let current =
+        swagger_spec("{type: object, required: [name], properties: {name: {type: string}}}");
+
+    let route_matcher = RouteMatcher::new(&base, &current);
+    let route_infos = route_matcher.get_all_routes_with_schemas();
+    assert_eq!(route_infos.len(), 1);
+    let schema_names: Vec<&str> = route_infos[0]
+        .request_schemas
+        .iter()
+        .map(|schema| schema.schema_name.as_str())
+        .collect();
+    assert_eq!(schema_names, vec!["User"]);
+
+    let base_schemas = &base.components.as_ref().unwrap().schemas;
+    let current_schemas = &current.components.as_ref().unwrap().schemas;
+    let schema_results =
+        SchemaMatcher::new(base_schemas, current_schemas, &base, &current).match_schemas();
+    let route_results = route_matcher.match_routes_with_schema_violations(&schema_results);
+
+    assert_eq!(route_results.len(), 1);
+    assert_eq!(route_results[0].name, "POST /users");
+    assert_eq!(
+        route_results[0].violations[0].name(),
+        "RequestSchemaViolation"
+    );
+    assert_eq!(route_results[0].change_level, ChangeLevel::Breaking);
+}
@@ -78,7 +78,16 @@ fn create_spec() -> Spec {
 /// Helper to add a path with operation to a spec
 fn add_path(spec: &mut Spec, path: &str, method: &str, op: Operation) {
     let paths = spec.paths.as_mut().unwrap();
-    let path_item = paths.entry(path.to_string()).or_insert_with(|| PathItem {
+    set_operation(paths, path, method, op);
+}
+
+/// Helper to add a webhook with operation to a spec
+fn add_webhook(spec: &mut Spec, name: &str, method: &str, op: Operation) {
+    set_operation(&mut spec.webhooks, name, method, op);
+}
+
+fn set_operation(items: &mut BTreeMap<String, PathItem>, path: &str, method: &str, op: Operation) {
+    let path_item = items.entry(path.to_string()).or_insert_with(|| PathItem {
         reference: None,
         summary: None,
         description: None,
@@ -103,6 +112,7 @@ fn add_path(spec: &mut Spec, path: &str, method: &str, op: Operation) {
         "patch" => path_item.patch = Some(op),
         "head" => path_item.head = Some(op),
         "options" => path_item.options = Some(op),
+        "trace" => path_item.trace = Some(op),
         _ => panic!("Unsupported HTTP method: {}", method),
     }
 }
@@ -114,7 +124,7 @@ fn add_path(spec: &mut Spec, path: &str, method: &str, op: Operation) {
 #[cfg(test)]
 mod route_tests {
     use super::*;
-    use apidrift::matcher::RouteMatcher;
+    use apidrift::matcher::{RouteKind, RouteMatcher};
     use apidrift::rules::route::*;
 
     #[test]
@@ -217,6 +227,22 @@ mod route_tests {
         assert_eq!(violations.len(), 0, "Should not detect route as removed");
     }
 
+    #[test]
+    fn test_route_deprecated_rule_no_detection() {
+        // Already deprecated routes, and routes no longer deprecated, are not reported
+        let mut deprecated = operation();
+        deprecated.deprecated = Some(true);
+
+        for (base, current) in [
+            (deprecated.clone(), deprecated.clone()),
+            (deprecated.clone(), operation()),
+        ] {
+            let violations =
+                RouteDeprecatedRule::detect("/users", "get", Some(&base), Some(&current));
+            assert_eq!(violations.len(), 0, "Should not detect route as deprecated");
+        }
+    }
+
     #[test]
     fn test_route_summary_changed_rule_detection() {
         let mut base = create_spec();
@@ -270,6 +296,31 @@ mod route_tests {
         );
     }
 
+    #[test]
+    fn test_route_deprecated_rule_detection() {
+        let mut base = create_spec();
+        let mut current = create_spec();
+
+        let mut current_op = operation();
+        current_op.deprecated = Some(true);
+
+        add_path(&mut base, "/users", "get", operation());
+        add_path(&mut current, "/users", "get", current_op.clone());
+
+        let matcher = RouteMatcher::new(&base, &current);
+        let results = matcher.match_routes();
+
+        let route = results.iter().find(|r| r.name == "GET /users").unwrap();
+        assert_eq!(route.violations.len(), 1);
+        assert_eq!(route.violations[0].name(), "RouteDeprecated");
+        assert_eq!(route.change_level, ChangeLevel::Warning);
+
+        // Staying deprecated is not reported again
+        let violations =
+            RouteDeprecatedRule::detect("/users", "get", Some(&current_op), Some(&current_op));
+        assert!(violations.is_empty());
+    }
+
     #[test]
     fn test_route_description_changed_rule_detection() {
         let mut base = create_spec();
@@ -746,4 +797,199 @@ mod route_tests {
         assert!(results.iter().any(|r| r.name.contains("PUT")));
         assert!(results.iter().any(|r| r.name.contains("PATCH")));
     }
+
+    #[test]
+    fn test_path_filter_skips_excluded_routes() {
+        let base = create_spec();
+        let mut current = create_spec();
+
+        add_path(&mut current, "/api/v2/users", "get", operation());
+        add_path(&mut current, "/api/v1/users", "get", operation());
+        add_path(&mut current, "/internal/debug", "get", operation());
+
+        let filter = apidrift::filter::MatchFilter::new(
+            &["/api/**".to_string()],
+            &["/api/v1/**".to_string()],
+            &[],
+        )
+        .unwrap();
+        let matcher = RouteMatcher::new(&base, &current).with_filter(filter);
+
+        let results = matcher.match_routes();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "GET /api/v2/users");
+
+        let route_infos = matcher.get_all_routes_with_schemas();
+        assert_eq!(route_infos.len(), 1);
+        assert_eq!(route_infos[0].path, "/api/v2/users");
+    }
+
+    #[test]
+    fn test_webhook_changes_use_inverted_direction() {
+        let mut base = create_spec();
+        let mut current = create_spec();
+
+        let mut op = operation();
+        op.responses = Some(BTreeMap::from([
+            ("200".to_string(), response("Received")),
+            ("410".to_string(), response("Unsubscribed")),
+        ]));
+        add_webhook(&mut base, "petCreated", "post", op);
+
+        let mut op = operation();
+        op.responses = Some(BTreeMap::from([("200".to_string(), response("Received"))]));
+        add_webhook(&mut current, "petCreated", "post", op.clone());
+        add_webhook(&mut current, "petDeleted", "post", op);
+
+        let matcher = RouteMatcher::new(&base, &current);
+        assert!(matcher.match_routes().is_empty());
+
+        let results = matcher.match_event_routes_with_schema_violations(&[]);
+        assert_eq!(results.len(), 2);
+
+        // Consumers answering with a removed status break the contract
+        let created = results
+            .iter()
+            .find(|r| r.name == "POST petCreated")
+            .unwrap();
+        assert_eq!(created.violations[0].name(), "ResponseStatusRemoved");
+        assert_eq!(created.change_level, ChangeLevel::Breaking);
+
+        let deleted = results
+            .iter()
+            .find(|r| r.name == "POST petDeleted")
+            .unwrap();
+        assert_eq!(deleted.violations[0].name(), "RouteAdded");
+
+        let infos = matcher.get_all_event_routes_with_schemas();
+        assert_eq!(infos.len(), 2);
+        assert_eq!(infos[0].kind, RouteKind::Webhook);
+    }
+
+    #[test]
+    fn test_callback_changes_are_detected() {
+        let mut base = create_spec();
+        let mut current = create_spec();
+
+        let callback = |description: &str| {
+            let mut paths = BTreeMap::new();
+            let mut callback_op = operation();
+            callback_op.description = Some(description.to_string());
+            set_operation(
+                &mut paths,
+                "{$request.body#/callbackUrl}",
+                "post",
+                callback_op,
+            );
+            let mut op = operation();
+            op.callbacks = BTreeMap::from([(
+                "onEvent".to_string(),
+                oas3::spec::Callback {
+                    paths,
+                    extensions: BTreeMap::new(),
+                },
+            )]);
+            op
+        };
+
+        add_path(&mut base, "/subscriptions", "post", callback("Old"));
+        add_path(&mut current, "/subscriptions", "post", callback("New"));
+
+        let matcher = RouteMatcher::new(&base, &current);
+        let results = matcher.match_event_routes_with_schema_violations(&[]);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].name,
+            "POST /subscriptions → onEvent → POST {$request.body#/callbackUrl}"
+        );
+        assert_eq!(results[0].violations[0].name(), "RouteDescriptionChanged");
+
+        let infos = matcher.get_all_event_routes_with_schemas();
+        assert_eq!(infos.len(), 1);
+        assert_eq!(infos[0].name(), results[0].name);
+        assert_eq!(
+            infos[0].kind,
+            RouteKind::Callback {
+                operation: "POST /subscriptions".to_string(),
+                name: "onEvent".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_trace_method_supported() {
+        let base = create_spec();
+        let mut current = create_spec();
+
+        add_path(&mut current, "/debug", "trace", operation());
+
+        let matcher = RouteMatcher::new(&base, &current);
+        let results = matcher.match_routes();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "TRACE /debug");
+        assert_eq!(matcher.get_all_routes_with_schemas().len(), 1);
+    }
+
+    #[test]
+    fn test_parameter_moved_to_path_level_is_not_reported() {
+        let mut base = create_spec();
+        let mut current = create_spec();
+
+        let mut op = operation();
+        op.parameters = vec![param!("id", ParameterIn::Path, true)];
+        add_path(&mut base, "/users/{id}", "get", op);
+
+        add_path(&mut current, "/users/{id}", "get", operation());
+        current
+            .paths
+            .as_mut()
+            .unwrap()
+            .get_mut("/users/{id}")
+            .unwrap()
+            .parameters = vec![param!("id", ParameterIn::Path, true)];
+
+        let matcher = RouteMatcher::new(&base, &current);
+        let results = matcher.match_routes();
+
+        assert!(
+            results.is_empty(),
+            "Moving a parameter to path level should not be a change: {:?}",
+            results
+        );
+    }
+
+    #[test]
+    fn test_path_level_required_parameter_added() {
+        let mut base = create_spec();
+        let mut current = create_spec();
+
+        add_path(&mut base, "/users", "get", operation());
+        add_path(&mut base, "/users", "post", operation());
+        add_path(&mut current, "/users", "get", operation());
+        add_path(&mut current, "/users", "post", operation());
+        current
+            .paths
+            .as_mut()
+            .unwrap()
+            .get_mut("/users")
+            .unwrap()
+            .parameters = vec![param!("X-Tenant", ParameterIn::Header, true)];
+
+        let matcher = RouteMatcher::new(&base, &current);
+        let results = matcher.match_routes();
+
+        assert_eq!(
+            results.len(),
+            2,
+            "Path-level parameter applies to every operation"
+        );
+        for result in &results {
+            assert!(result
+                .violations
+                .iter()
+                .any(|v| v.name() == "RequiredParameterAdded"));
+        }
+    }
 }